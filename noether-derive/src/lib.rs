@@ -0,0 +1,482 @@
+//! Derive macros for the algebraic marker traits of `noether`.
+//!
+//! Each derive emits the empty marker impls which the blanket hierarchy of `noether` uses to
+//! classify a type. The derives named after a single marker trait emit that trait alone, while
+//! the structure derives (`Ring`, `Field`, ...) emit every marker the structure requires.
+//!
+//! The markers are promises which the compiler cannot verify. Adding a `#[noether(...)]`
+//! attribute with a value generator makes the derive also emit a `#[cfg(test)]` module which
+//! runs the matching law checker from `noether::laws` against the type:
+//!
+//! ```ignore
+//! #[derive(Clone, Copy, Debug, PartialEq, Field)]
+//! #[noether(generator = random_element, samples = 1000)]
+//! struct Fp(u64);
+//! ```
+//!
+//! `samples` is optional and defaults to 1000.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Ident, LitInt, Member, Path, Type};
+
+// Names of the marker traits in `noether`.
+const COMMUTATIVE_ADDITION: &str = "CommutativeAddition";
+const ASSOCIATIVE_ADDITION: &str = "AssociativeAddition";
+const COMMUTATIVE_MULTIPLICATION: &str = "CommutativeMultiplication";
+const ASSOCIATIVE_MULTIPLICATION: &str = "AssociativeMultiplication";
+const DISTRIBUTIVE: &str = "Distributive";
+const NO_ZERO_DIVISORS: &str = "NoZeroDivisors";
+
+/// The law checks requested through the `#[noether(...)]` attribute.
+struct LawTests {
+    generator: Path,
+    samples: usize,
+}
+
+/// Parses the `#[noether(generator = path, samples = n)]` attribute, if present.
+fn parse_law_tests(input: &DeriveInput) -> syn::Result<Option<LawTests>> {
+    let mut generator = None;
+    let mut samples = 1000;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("noether")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("generator") {
+                generator = Some(meta.value()?.parse::<Path>()?);
+                Ok(())
+            } else if meta.path.is_ident("samples") {
+                samples = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `generator` or `samples`"))
+            }
+        })?;
+    }
+    match generator {
+        Some(generator) => {
+            if !input.generics.params.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &input.generics,
+                    "law tests can only be generated for types without generic parameters",
+                ));
+            }
+            Ok(Some(LawTests { generator, samples }))
+        }
+        None => Ok(None),
+    }
+}
+
+/// The optional traits requested through the `#[component_wise(...)]` attribute.
+#[derive(Default)]
+struct ComponentWiseOptions {
+    select: bool,
+    exact_division: bool,
+}
+
+/// Parses the `#[component_wise(select, exact_division)]` attribute, if present.
+fn parse_component_wise_options(input: &DeriveInput) -> syn::Result<ComponentWiseOptions> {
+    let mut options = ComponentWiseOptions::default();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("component_wise"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("select") {
+                options.select = true;
+                Ok(())
+            } else if meta.path.is_ident("exact_division") {
+                options.exact_division = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `select` or `exact_division`"))
+            }
+        })?;
+    }
+    Ok(options)
+}
+
+/// Emits the marker impls and, when requested, a test module running `checker`.
+fn derive_markers(input: DeriveInput, markers: &[&str], checker: &str) -> TokenStream {
+    let law_tests = match parse_law_tests(&input) {
+        Ok(law_tests) => law_tests,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let impls = markers.iter().map(|marker| {
+        let marker = Ident::new(marker, Span::call_site());
+        quote! {
+            impl #impl_generics ::noether::#marker for #name #ty_generics #where_clause {}
+        }
+    });
+
+    let tests = law_tests.map(|LawTests { generator, samples }| {
+        let module = format_ident!("__noether_{}_laws_{}", checker, name);
+        let check = format_ident!("check_{}", checker);
+        quote! {
+            #[cfg(test)]
+            #[allow(non_snake_case)]
+            mod #module {
+                use super::*;
+
+                #[test]
+                fn laws() {
+                    if let Err(violation) =
+                        ::noether::laws::#check::<#name, _>(#generator, #samples)
+                    {
+                        panic!("{}", violation);
+                    }
+                }
+            }
+        }
+    });
+
+    let expanded: TokenStream2 = quote! {
+        #(#impls)*
+        #tests
+    };
+    expanded.into()
+}
+
+/// Derives `noether::CommutativeAddition`: a + b = b + a.
+#[proc_macro_derive(CommutativeAddition, attributes(noether))]
+pub fn derive_commutative_addition(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(input, &[COMMUTATIVE_ADDITION], "commutative_addition")
+}
+
+/// Derives `noether::AssociativeAddition`: (a + b) + c = a + (b + c).
+#[proc_macro_derive(AssociativeAddition, attributes(noether))]
+pub fn derive_associative_addition(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(input, &[ASSOCIATIVE_ADDITION], "associative_addition")
+}
+
+/// Derives `noether::CommutativeMultiplication`: a * b = b * a.
+#[proc_macro_derive(CommutativeMultiplication, attributes(noether))]
+pub fn derive_commutative_multiplication(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(
+        input,
+        &[COMMUTATIVE_MULTIPLICATION],
+        "commutative_multiplication",
+    )
+}
+
+/// Derives `noether::AssociativeMultiplication`: (a * b) * c = a * (b * c).
+#[proc_macro_derive(AssociativeMultiplication, attributes(noether))]
+pub fn derive_associative_multiplication(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(
+        input,
+        &[ASSOCIATIVE_MULTIPLICATION],
+        "associative_multiplication",
+    )
+}
+
+/// Derives `noether::Distributive`: a * (b + c) = (a * b) + (a * c).
+#[proc_macro_derive(Distributive, attributes(noether))]
+pub fn derive_distributive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(input, &[DISTRIBUTIVE], "distributive")
+}
+
+/// Derives `noether::NoZeroDivisors`: a * b = 0 implies a = 0 or b = 0.
+#[proc_macro_derive(NoZeroDivisors, attributes(noether))]
+pub fn derive_no_zero_divisors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(input, &[NO_ZERO_DIVISORS], "no_zero_divisors")
+}
+
+/// Derives the markers of an additive abelian group: associative and commutative addition.
+#[proc_macro_derive(AdditiveAbelianGroup, attributes(noether))]
+pub fn derive_additive_abelian_group(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(
+        input,
+        &[ASSOCIATIVE_ADDITION, COMMUTATIVE_ADDITION],
+        "additive_abelian_group",
+    )
+}
+
+/// Derives the markers of a ring: associative and commutative addition, associative
+/// multiplication and distributivity.
+#[proc_macro_derive(Ring, attributes(noether))]
+pub fn derive_ring(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(
+        input,
+        &[
+            ASSOCIATIVE_ADDITION,
+            COMMUTATIVE_ADDITION,
+            ASSOCIATIVE_MULTIPLICATION,
+            DISTRIBUTIVE,
+        ],
+        "ring",
+    )
+}
+
+/// Derives the markers of a commutative ring: the markers of a ring and commutative
+/// multiplication.
+#[proc_macro_derive(CommutativeRing, attributes(noether))]
+pub fn derive_commutative_ring(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(
+        input,
+        &[
+            ASSOCIATIVE_ADDITION,
+            COMMUTATIVE_ADDITION,
+            ASSOCIATIVE_MULTIPLICATION,
+            COMMUTATIVE_MULTIPLICATION,
+            DISTRIBUTIVE,
+        ],
+        "commutative_ring",
+    )
+}
+
+/// Derives the markers of a field: the markers of a commutative ring and the absence of zero
+/// divisors. The generated law tests additionally check inverses and division.
+#[proc_macro_derive(Field, attributes(noether))]
+pub fn derive_field(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive_markers(
+        input,
+        &[
+            ASSOCIATIVE_ADDITION,
+            COMMUTATIVE_ADDITION,
+            ASSOCIATIVE_MULTIPLICATION,
+            COMMUTATIVE_MULTIPLICATION,
+            DISTRIBUTIVE,
+            NO_ZERO_DIVISORS,
+        ],
+        "field",
+    )
+}
+
+/// Derives component-wise arithmetic for a struct, making a newtype or product of algebraic
+/// structures an algebraic structure itself.
+///
+/// The ring operators (`Add`, `AddAssign`, `Sub`, `SubAssign`, `Neg`, `Mul`, `MulAssign`), the
+/// identities (`Zero`, `One`) and the five ring marker traits are forwarded to the fields. Each
+/// impl only applies when every field supports the forwarded trait, so a product whose fields are
+/// all rings becomes a `Ring` through the blanket impls.
+///
+/// Two traits whose supertraits constrain the struct itself are only forwarded on request,
+/// through a `#[component_wise(...)]` attribute:
+/// - `select` forwards `ConditionallySelectable`, which requires the struct to be `Clone`.
+/// - `exact_division` forwards `ExactDivision`, which requires the struct to be a `Ring`.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Debug, PartialEq, ComponentWise)]
+/// #[component_wise(select, exact_division)]
+/// struct Pair(Fp, Fp);
+/// ```
+///
+/// Division (`Inv`, `Div`, `DivAssign`) and `NoZeroDivisors` are only forwarded by a struct with
+/// a single field. A product of two or more non-trivial rings has zero divisors,
+/// (1, 0) · (0, 1) = (0, 0), and an element such as (1, 0) has no inverse, so inverting
+/// component-wise would not be division.
+///
+/// Do not combine with the marker derives on the same type, since this derive already emits the
+/// marker impls.
+#[proc_macro_derive(ComponentWise, attributes(component_wise))]
+pub fn derive_component_wise(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match component_wise(&input) {
+        Ok(expanded) => expanded.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Builds the component-wise impls of `derive(ComponentWise)`.
+fn component_wise(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) if !data.fields.is_empty() => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "ComponentWise can only be derived for structs with at least one field",
+            ))
+        }
+    };
+    let options = parse_component_wise_options(input)?;
+    let members: Vec<Member> = fields.members().collect();
+    let types: Vec<&Type> = fields.iter().map(|f| &f.ty).collect();
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    // Each impl is guarded by a bound on every field type. The bounds are higher-ranked so that
+    // a bound on a concrete field type which does not hold leaves the impl inapplicable, rather
+    // than being rejected as trivially false.
+    let guarded = |bound: &dyn Fn(&Type) -> TokenStream2| {
+        let mut generics = input.generics.clone();
+        let where_clause = generics.make_where_clause();
+        for ty in &types {
+            let bound = bound(ty);
+            where_clause
+                .predicates
+                .push(parse_quote!(for<'__noether> #ty: #bound));
+        }
+        quote!(#where_clause)
+    };
+
+    let mut impls = Vec::new();
+
+    // Division is only component-wise for a newtype; see the documentation of the derive.
+    let single = members.len() == 1;
+
+    let mut binary = vec![
+        (quote!(Add), quote!(add), quote!(+)),
+        (quote!(Sub), quote!(sub), quote!(-)),
+        (quote!(Mul), quote!(mul), quote!(*)),
+    ];
+    let mut assign = vec![
+        (quote!(AddAssign), quote!(add_assign), quote!(+=)),
+        (quote!(SubAssign), quote!(sub_assign), quote!(-=)),
+        (quote!(MulAssign), quote!(mul_assign), quote!(*=)),
+    ];
+    if single {
+        binary.push((quote!(Div), quote!(div), quote!(/)));
+        assign.push((quote!(DivAssign), quote!(div_assign), quote!(/=)));
+    }
+
+    for (op, method, symbol) in binary {
+        let where_clause = guarded(&|ty| quote!(::core::ops::#op<Output = #ty>));
+        impls.push(quote! {
+            impl #impl_generics ::core::ops::#op for #name #ty_generics #where_clause {
+                type Output = Self;
+
+                fn #method(self, rhs: Self) -> Self {
+                    Self { #(#members: self.#members #symbol rhs.#members),* }
+                }
+            }
+        });
+    }
+
+    for (op, method, symbol) in assign {
+        let where_clause = guarded(&|_| quote!(::core::ops::#op));
+        impls.push(quote! {
+            impl #impl_generics ::core::ops::#op for #name #ty_generics #where_clause {
+                fn #method(&mut self, rhs: Self) {
+                    #(self.#members #symbol rhs.#members;)*
+                }
+            }
+        });
+    }
+
+    let where_clause = guarded(&|ty| quote!(::core::ops::Neg<Output = #ty>));
+    impls.push(quote! {
+        impl #impl_generics ::core::ops::Neg for #name #ty_generics #where_clause {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { #(#members: -self.#members),* }
+            }
+        }
+    });
+
+    if single {
+        let where_clause = guarded(&|ty| quote!(::noether::num_traits::Inv<Output = #ty>));
+        impls.push(quote! {
+            impl #impl_generics ::noether::num_traits::Inv for #name #ty_generics #where_clause {
+                type Output = Self;
+
+                fn inv(self) -> Self {
+                    Self { #(#members: ::noether::num_traits::Inv::inv(self.#members)),* }
+                }
+            }
+        });
+    }
+
+    let where_clause = guarded(&|_| quote!(::noether::num_traits::Zero));
+    impls.push(quote! {
+        impl #impl_generics ::noether::num_traits::Zero for #name #ty_generics #where_clause {
+            fn zero() -> Self {
+                Self { #(#members: ::noether::num_traits::Zero::zero()),* }
+            }
+
+            fn is_zero(&self) -> bool {
+                true #(&& ::noether::num_traits::Zero::is_zero(&self.#members))*
+            }
+        }
+    });
+
+    let where_clause = guarded(&|_| quote!(::noether::num_traits::One));
+    impls.push(quote! {
+        impl #impl_generics ::noether::num_traits::One for #name #ty_generics #where_clause {
+            fn one() -> Self {
+                Self { #(#members: ::noether::num_traits::One::one()),* }
+            }
+        }
+    });
+
+    if options.exact_division {
+        let where_clause = guarded(&|_| quote!(::noether::ExactDivision));
+        impls.push(quote! {
+            impl #impl_generics ::noether::ExactDivision for #name #ty_generics #where_clause {
+                fn div_exact(&self, n: u32) -> ::core::option::Option<Self> {
+                    ::core::option::Option::Some(Self {
+                        #(#members: ::noether::ExactDivision::div_exact(&self.#members, n)?),*
+                    })
+                }
+            }
+        });
+    }
+
+    if options.select {
+        let where_clause = guarded(&|_| quote!(::noether::ConditionallySelectable));
+        impls.push(quote! {
+            impl #impl_generics ::noether::ConditionallySelectable for #name #ty_generics #where_clause {
+                fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+                    Self {
+                        #(#members: ::noether::ConditionallySelectable::conditional_select(
+                            &a.#members,
+                            &b.#members,
+                            choice,
+                        )),*
+                    }
+                }
+            }
+        });
+    }
+
+    let markers: &[&str] = if single {
+        &[
+            COMMUTATIVE_ADDITION,
+            ASSOCIATIVE_ADDITION,
+            COMMUTATIVE_MULTIPLICATION,
+            ASSOCIATIVE_MULTIPLICATION,
+            DISTRIBUTIVE,
+            NO_ZERO_DIVISORS,
+        ]
+    } else {
+        &[
+            COMMUTATIVE_ADDITION,
+            ASSOCIATIVE_ADDITION,
+            COMMUTATIVE_MULTIPLICATION,
+            ASSOCIATIVE_MULTIPLICATION,
+            DISTRIBUTIVE,
+        ]
+    };
+    for &marker in markers {
+        // A struct is bounded if any of its fields is.
+        let body = (marker == ASSOCIATIVE_ADDITION).then(|| {
+            quote! {
+                const BOUNDED: bool =
+                    false #(|| <#types as ::noether::AssociativeAddition>::BOUNDED)*;
+            }
+        });
+        let marker = Ident::new(marker, Span::call_site());
+        let where_clause = guarded(&|_| quote!(::noether::#marker));
+        impls.push(quote! {
+            impl #impl_generics ::noether::#marker for #name #ty_generics #where_clause {
+                #body
+            }
+        });
+    }
+
+    Ok(quote!(#(#impls)*))
+}
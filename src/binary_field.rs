@@ -0,0 +1,875 @@
+//! Binary extension fields GF(2ⁿ).
+//!
+//! An element of GF(2ⁿ) is a polynomial of degree below n over GF(2), stored as the bits of an
+//! integer: bit i holds the coefficient of xⁱ. Addition is XOR, and multiplication is carry-less
+//! multiplication followed by reduction modulo an irreducible polynomial of degree n.
+
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    ConditionallySelectable, Distributive, EuclideanValuation, ExactDivision, FiniteField,
+    FiniteSet, NoZeroDivisors, UInt,
+};
+use num_traits::{Euclid, Inv, One, Zero};
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Returns the carry-less product of `a` and `b`.
+const fn clmul(a: u64, b: u64) -> u128 {
+    let mut product = 0u128;
+    let mut i = 0;
+    while i < 64 {
+        let mask = 0u128.wrapping_sub(((b >> i) & 1) as u128);
+        product ^= ((a as u128) << i) & mask;
+        i += 1;
+    }
+    product
+}
+
+/// Reduces a product of two polynomials of degree below n modulo xⁿ + `poly`.
+const fn reduce(mut product: u128, n: u32, poly: u64) -> u64 {
+    let mut i = 2 * n - 1;
+    while i > n {
+        i -= 1;
+        // Replace the term xⁱ = xⁿ · xⁱ⁻ⁿ by poly · xⁱ⁻ⁿ.
+        let mask = 0u128.wrapping_sub((product >> i) & 1);
+        product ^= ((1u128 << i) | ((poly as u128) << (i - n))) & mask;
+    }
+    if n < 64 {
+        product &= (1u128 << n) - 1;
+    }
+    product as u64
+}
+
+/// Returns the degree of a nonzero polynomial.
+const fn degree(a: u128) -> u32 {
+    127 - a.leading_zeros()
+}
+
+/// Returns the greatest common divisor of two polynomials.
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        while a != 0 && degree(a) >= degree(b) {
+            a ^= b << (degree(a) - degree(b));
+        }
+        (a, b) = (b, a);
+    }
+    a
+}
+
+/// Returns whether xⁿ + `poly` is irreducible over GF(2), by Ben-Or's test.
+const fn is_irreducible(n: u32, poly: u64) -> bool {
+    if n == 0 || (n < 64 && poly >> n != 0) {
+        return false;
+    }
+    let f = (1u128 << n) | poly as u128;
+    let x = if n == 1 { poly } else { 2 };
+    // f is irreducible if and only if gcd(f, x^(2ⁱ) - x) = 1 for every 1 ≤ i ≤ n / 2.
+    let mut power = x;
+    let mut i = 1;
+    while i <= n / 2 {
+        power = reduce(clmul(power, power), n, poly);
+        if gcd(f, (power ^ x) as u128) != 1 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Represents the binary field GF(2ᴺ) = GF(2)\[x\] / (xᴺ + `POLY`), for 1 ≤ N ≤ 64.
+///
+/// # Mathematical Definition
+/// GF(2ᴺ) is the set of polynomials over GF(2) of degree below N, with addition and
+/// multiplication modulo an irreducible polynomial f of degree N.
+///
+/// # Properties
+/// - GF(2ᴺ) is a field of characteristic 2 with 2ᴺ elements
+/// - Every element is its own additive inverse: a + a = 0
+///
+/// `POLY` holds the coefficients of f below xᴺ; for example `BinaryField<8, 0x1B>` is the field
+/// of AES. Inversion, division and the [`FiniteField`] methods contain a constant assertion that
+/// f is irreducible, which turns any use of them with a reducible polynomial into a compile-time
+/// error. Multiplication runs in constant time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BinaryField<const N: u32, const POLY: u64>(u64);
+
+impl<const N: u32, const POLY: u64> BinaryField<N, POLY> {
+    /// Evaluates to `()` when the degree is supported, and fails to compile otherwise.
+    const VALID_DEGREE: () = assert!(
+        N >= 1 && N <= 64,
+        "the degree of a BinaryField must be between 1 and 64"
+    );
+
+    /// Evaluates to `()` when the polynomial is irreducible, and fails to compile otherwise.
+    const IRREDUCIBLE: () = assert!(
+        is_irreducible(N, POLY),
+        "BinaryField is only a field when its polynomial is irreducible"
+    );
+
+    /// Creates the element whose coefficients are the low N bits of `bits`.
+    pub const fn new(bits: u64) -> Self {
+        let () = Self::VALID_DEGREE;
+        BinaryField(bits & (u64::MAX >> (64 - N)))
+    }
+
+    /// Returns the coefficients of the element as bits.
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl<const N: u32, const POLY: u64> fmt::Display for BinaryField<N, POLY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Add for BinaryField<N, POLY> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        BinaryField(self.0 ^ rhs.0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Sub for BinaryField<N, POLY> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        BinaryField(self.0 ^ rhs.0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Neg for BinaryField<N, POLY> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<const N: u32, const POLY: u64> Mul for BinaryField<N, POLY> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        BinaryField(reduce(clmul(self.0, rhs.0), N, POLY))
+    }
+}
+
+impl<const N: u32, const POLY: u64> Inv for BinaryField<N, POLY> {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse a⁻¹ = a^(2ᴺ - 2).
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    fn inv(self) -> Self {
+        let () = Self::IRREDUCIBLE;
+        assert!(self.0 != 0, "attempt to invert zero in BinaryField");
+        // 2ᴺ - 2 = 2 + 4 + ... + 2ᴺ⁻¹
+        let mut square = self;
+        let mut result = Self::one();
+        for _ in 1..N {
+            square *= square;
+            result *= square;
+        }
+        result
+    }
+}
+
+impl<const N: u32, const POLY: u64> Div for BinaryField<N, POLY> {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<const N: u32, const POLY: u64> Rem for BinaryField<N, POLY> {
+    type Output = Self;
+
+    /// Returns the remainder of field division, which is always zero.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        let () = Self::IRREDUCIBLE;
+        assert!(
+            rhs.0 != 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        BinaryField(0)
+    }
+}
+
+impl<const N: u32, const POLY: u64> AddAssign for BinaryField<N, POLY> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: u32, const POLY: u64> SubAssign for BinaryField<N, POLY> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: u32, const POLY: u64> MulAssign for BinaryField<N, POLY> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: u32, const POLY: u64> DivAssign for BinaryField<N, POLY> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: u32, const POLY: u64> RemAssign for BinaryField<N, POLY> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const N: u32, const POLY: u64> Zero for BinaryField<N, POLY> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const N: u32, const POLY: u64> One for BinaryField<N, POLY> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const N: u32, const POLY: u64> Euclid for BinaryField<N, POLY> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl<const N: u32, const POLY: u64> EuclideanValuation for BinaryField<N, POLY> {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(!self.is_zero())
+    }
+}
+
+impl<const N: u32, const POLY: u64> CommutativeAddition for BinaryField<N, POLY> {}
+impl<const N: u32, const POLY: u64> AssociativeAddition for BinaryField<N, POLY> {}
+impl<const N: u32, const POLY: u64> CommutativeMultiplication for BinaryField<N, POLY> {}
+impl<const N: u32, const POLY: u64> AssociativeMultiplication for BinaryField<N, POLY> {}
+impl<const N: u32, const POLY: u64> Distributive for BinaryField<N, POLY> {}
+impl<const N: u32, const POLY: u64> NoZeroDivisors for BinaryField<N, POLY> {}
+
+// 2 = 0 in characteristic 2, so binary fields keep the default, which divides by nothing.
+impl<const N: u32, const POLY: u64> ExactDivision for BinaryField<N, POLY> {}
+
+impl<const N: u32, const POLY: u64> ConditionallySelectable for BinaryField<N, POLY> {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        let mask = 0u64.wrapping_sub(choice);
+        BinaryField((a.0 & !mask) | (b.0 & mask))
+    }
+}
+
+impl<const N: u32, const POLY: u64> FiniteSet for BinaryField<N, POLY> {
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..=u64::MAX >> (64 - N)).map(Self::new)
+    }
+}
+
+impl<const N: u32, const POLY: u64> FiniteField for BinaryField<N, POLY> {
+    type ScalarType = u128;
+
+    fn characteristic() -> u128 {
+        let () = Self::IRREDUCIBLE;
+        2
+    }
+
+    fn order() -> u128 {
+        let () = Self::IRREDUCIBLE;
+        1 << N
+    }
+
+    fn extension_degree() -> u32 {
+        let () = Self::IRREDUCIBLE;
+        N
+    }
+}
+
+/// The AES polynomial x⁸ + x⁴ + x³ + x + 1, without its leading term.
+const AES_POLY: u64 = 0x1B;
+
+/// Powers of the generator x + 1 of GF(2⁸)*, repeated so that the sum of two logarithms can
+/// index the table directly.
+const EXP: [u8; 510] = {
+    let mut table = [0; 510];
+    let mut power = 1u64;
+    let mut i = 0;
+    while i < 510 {
+        table[i] = power as u8;
+        power = reduce(clmul(power, 0x03), 8, AES_POLY);
+        i += 1;
+    }
+    table
+};
+
+/// Discrete logarithms to base x + 1; the entry for zero is unused.
+const LOG: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 255 {
+        table[EXP[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+};
+
+/// Represents GF(2⁸) with the AES and Reed–Solomon polynomial x⁸ + x⁴ + x³ + x + 1.
+///
+/// This is the same field as `BinaryField<8, 0x1B>`, but multiplication and inversion use
+/// logarithm tables instead of carry-less multiplication. The table lookups depend on the
+/// operands, so unlike [`BinaryField`] these operations do not run in constant time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Gf256(u8);
+
+impl Gf256 {
+    /// Creates the element whose coefficients are the bits of `bits`.
+    pub const fn new(bits: u8) -> Self {
+        Gf256(bits)
+    }
+
+    /// Returns the coefficients of the element as bits.
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Gf256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#04x}", self.0)
+    }
+}
+
+impl Add for Gf256 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl Sub for Gf256 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Gf256(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for Gf256 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Mul for Gf256 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        if self.0 == 0 || rhs.0 == 0 {
+            return Gf256(0);
+        }
+        Gf256(EXP[LOG[self.0 as usize] as usize + LOG[rhs.0 as usize] as usize])
+    }
+}
+
+impl Inv for Gf256 {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    fn inv(self) -> Self {
+        assert!(self.0 != 0, "attempt to invert zero in Gf256");
+        Gf256(EXP[255 - LOG[self.0 as usize] as usize])
+    }
+}
+
+impl Div for Gf256 {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl Rem for Gf256 {
+    type Output = Self;
+
+    /// Returns the remainder of field division, which is always zero.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            rhs.0 != 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        Gf256(0)
+    }
+}
+
+impl AddAssign for Gf256 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Gf256 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Gf256 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Gf256 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl RemAssign for Gf256 {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl Zero for Gf256 {
+    fn zero() -> Self {
+        Gf256(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Gf256 {
+    fn one() -> Self {
+        Gf256(1)
+    }
+}
+
+impl Euclid for Gf256 {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl EuclideanValuation for Gf256 {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(!self.is_zero())
+    }
+}
+
+impl CommutativeAddition for Gf256 {}
+impl AssociativeAddition for Gf256 {}
+impl CommutativeMultiplication for Gf256 {}
+impl AssociativeMultiplication for Gf256 {}
+impl Distributive for Gf256 {}
+impl NoZeroDivisors for Gf256 {}
+impl ExactDivision for Gf256 {}
+
+impl ConditionallySelectable for Gf256 {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        let mask = 0u8.wrapping_sub(choice as u8);
+        Gf256((a.0 & !mask) | (b.0 & mask))
+    }
+}
+
+impl FiniteSet for Gf256 {
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..=u8::MAX).map(Gf256)
+    }
+}
+
+impl FiniteField for Gf256 {
+    type ScalarType = u64;
+
+    fn characteristic() -> u64 {
+        2
+    }
+
+    fn order() -> u64 {
+        256
+    }
+
+    fn extension_degree() -> u32 {
+        8
+    }
+}
+
+/// Represents GF(2¹²⁸) with the GHASH polynomial x¹²⁸ + x⁷ + x² + x + 1.
+///
+/// Bit i of the value holds the coefficient of xⁱ. GCM serializes field elements with the bits
+/// of each byte reflected, so GHASH blocks must be bit-reversed (`u128::reverse_bits` of the
+/// big-endian block) before conversion. Multiplication is a Karatsuba carry-less multiplication
+/// with a shift-and-XOR reduction, and runs in constant time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Gf2_128(u128);
+
+impl Gf2_128 {
+    /// Creates the element whose coefficients are the bits of `bits`.
+    pub const fn new(bits: u128) -> Self {
+        Gf2_128(bits)
+    }
+
+    /// Returns the coefficients of the element as bits.
+    pub const fn value(&self) -> u128 {
+        self.0
+    }
+}
+
+impl fmt::Display for Gf2_128 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl Add for Gf2_128 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self {
+        Gf2_128(self.0 ^ rhs.0)
+    }
+}
+
+impl Sub for Gf2_128 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self {
+        Gf2_128(self.0 ^ rhs.0)
+    }
+}
+
+impl Neg for Gf2_128 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl Mul for Gf2_128 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (a0, a1) = (self.0 as u64, (self.0 >> 64) as u64);
+        let (b0, b1) = (rhs.0 as u64, (rhs.0 >> 64) as u64);
+        let low = clmul(a0, b0);
+        let high = clmul(a1, b1);
+        let middle = clmul(a0 ^ a1, b0 ^ b1) ^ low ^ high;
+        let low = low ^ (middle << 64);
+        let high = high ^ (middle >> 64);
+
+        // x¹²⁸ = x⁷ + x² + x + 1, so high · x¹²⁸ = high · (x⁷ + x² + x + 1). The bits shifted out
+        // above x¹²⁷ are reduced the same way, and then fit.
+        let folded = high ^ (high << 1) ^ (high << 2) ^ (high << 7);
+        let overflow = (high >> 127) ^ (high >> 126) ^ (high >> 121);
+        Gf2_128(low ^ folded ^ overflow ^ (overflow << 1) ^ (overflow << 2) ^ (overflow << 7))
+    }
+}
+
+impl Inv for Gf2_128 {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse a⁻¹ = a^(2¹²⁸ - 2).
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    fn inv(self) -> Self {
+        assert!(self.0 != 0, "attempt to invert zero in Gf2_128");
+        let mut square = self;
+        let mut result = Self::one();
+        for _ in 1..128 {
+            square *= square;
+            result *= square;
+        }
+        result
+    }
+}
+
+impl Div for Gf2_128 {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl Rem for Gf2_128 {
+    type Output = Self;
+
+    /// Returns the remainder of field division, which is always zero.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            rhs.0 != 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        Gf2_128(0)
+    }
+}
+
+impl AddAssign for Gf2_128 {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Gf2_128 {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Gf2_128 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Gf2_128 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl RemAssign for Gf2_128 {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl Zero for Gf2_128 {
+    fn zero() -> Self {
+        Gf2_128(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Gf2_128 {
+    fn one() -> Self {
+        Gf2_128(1)
+    }
+}
+
+impl Euclid for Gf2_128 {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl EuclideanValuation for Gf2_128 {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(!self.is_zero())
+    }
+}
+
+impl CommutativeAddition for Gf2_128 {}
+impl AssociativeAddition for Gf2_128 {}
+impl CommutativeMultiplication for Gf2_128 {}
+impl AssociativeMultiplication for Gf2_128 {}
+impl Distributive for Gf2_128 {}
+impl NoZeroDivisors for Gf2_128 {}
+impl ExactDivision for Gf2_128 {}
+
+impl ConditionallySelectable for Gf2_128 {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        let mask = 0u128.wrapping_sub(choice as u128);
+        Gf2_128((a.0 & !mask) | (b.0 & mask))
+    }
+}
+
+impl FiniteField for Gf2_128 {
+    type ScalarType = UInt<3>;
+
+    fn characteristic() -> UInt<3> {
+        UInt::from(2)
+    }
+
+    fn order() -> UInt<3> {
+        UInt::from_limbs([0, 0, 1])
+    }
+
+    fn extension_degree() -> u32 {
+        128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::{check_field, check_field_exhaustive};
+
+    /// The AES S-box: inversion in GF(2⁸), zero to zero, followed by an affine map over GF(2).
+    fn sbox(x: u8) -> u8 {
+        let b = if x == 0 {
+            0
+        } else {
+            Gf256::new(x).inv().value()
+        };
+        b ^ b.rotate_left(1) ^ b.rotate_left(2) ^ b.rotate_left(3) ^ b.rotate_left(4) ^ 0x63
+    }
+
+    /// The inverse AES S-box: the inverse affine map followed by inversion.
+    fn inverse_sbox(y: u8) -> u8 {
+        let b = y.rotate_left(1) ^ y.rotate_left(3) ^ y.rotate_left(6) ^ 0x05;
+        if b == 0 {
+            0
+        } else {
+            Gf256::new(b).inv().value()
+        }
+    }
+
+    /// Converts a GCM block, read as a big-endian integer, into a field element.
+    fn block(bytes: u128) -> Gf2_128 {
+        Gf2_128::new(bytes.reverse_bits())
+    }
+
+    #[test]
+    fn small_fields_are_exhaustively_fields() {
+        check_field_exhaustive::<Gf256>().unwrap();
+        check_field_exhaustive::<BinaryField<4, 0x3>>().unwrap();
+        check_field_exhaustive::<BinaryField<1, 0x1>>().unwrap();
+    }
+
+    #[test]
+    fn gf256_agrees_with_the_generic_aes_field() {
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                let product = Gf256::new(a) * Gf256::new(b);
+                let generic = BinaryField::<8, 0x1B>::new(a as u64) * BinaryField::new(b as u64);
+                assert_eq!(product.value() as u64, generic.value());
+            }
+        }
+    }
+
+    #[test]
+    fn frobenius_squares() {
+        let a = Gf256::new(0x53);
+        assert_eq!(a.absolute_frobenius_map(1), a * a);
+        assert_eq!(
+            a.absolute_frobenius_map(3),
+            a.absolute_frobenius_map(2) * a.absolute_frobenius_map(2)
+        );
+        assert_eq!(a.absolute_frobenius_map(8), a);
+        let b = Gf2_128::new(0x9E3779B97F4A7C15F39CC0605CEDC835);
+        assert_eq!(b.absolute_frobenius_map(129), b * b);
+    }
+
+    #[test]
+    fn gf256_matches_fips_197() {
+        // The worked multiplications of FIPS 197, section 4.2.
+        assert_eq!(Gf256::new(0x57) * Gf256::new(0x83), Gf256::new(0xC1));
+        assert_eq!(Gf256::new(0x57) * Gf256::new(0x13), Gf256::new(0xFE));
+        assert_eq!(Gf256::new(0x53).inv(), Gf256::new(0xCA));
+
+        // The first row of the S-box, and scattered entries of both tables.
+        let row: Vec<u8> = (0..16).map(sbox).collect();
+        assert_eq!(
+            row,
+            [
+                0x63, 0x7C, 0x77, 0x7B, 0xF2, 0x6B, 0x6F, 0xC5, 0x30, 0x01, 0x67, 0x2B, 0xFE, 0xD7,
+                0xAB, 0x76
+            ]
+        );
+        assert_eq!(sbox(0x53), 0xED);
+        assert_eq!(sbox(0x10), 0xCA);
+        assert_eq!(sbox(0xFF), 0x16);
+        assert_eq!(inverse_sbox(0x00), 0x52);
+        assert_eq!(inverse_sbox(0x63), 0x00);
+        assert_eq!(inverse_sbox(0xED), 0x53);
+        assert_eq!(inverse_sbox(0xFF), 0x7D);
+        for x in 0..=255u8 {
+            assert_eq!(inverse_sbox(sbox(x)), x);
+        }
+    }
+
+    #[test]
+    fn gf2_128_matches_gcm_test_case_2() {
+        // GHASH of test case 2 of the GCM specification: no additional data and the single
+        // ciphertext block C under the hash key H.
+        let h = block(0x66e94bd4ef8a2c3b884cfa59ca342b2e);
+        let c = block(0x0388dace60b6a392f328c2b971b2fe78);
+        let lengths = block(0x80);
+        let x1 = c * h;
+        assert_eq!(x1, block(0x5e2ec746917062882c85b0685353deb7));
+        let x2 = (x1 + lengths) * h;
+        assert_eq!(x2, block(0xf38cbb1ad69223dcc3457ae5b6b0f885));
+    }
+
+    #[test]
+    fn gf2_128_reduces_by_the_ghash_polynomial() {
+        let x = Gf2_128::new(2);
+        let x127 = Gf2_128::new(1 << 127);
+        // x¹²⁸ = x⁷ + x² + x + 1.
+        assert_eq!(x127 * x, Gf2_128::new(0x87));
+        assert_eq!(x127 * x127.inv(), Gf2_128::one());
+        assert_eq!(Gf2_128::order(), UInt::from_limbs([0, 0, 1]));
+        let mut seed = 0x9E3779B97F4A7C15F39CC0605CEDC835u128;
+        let gen = || {
+            seed = seed.rotate_left(17).wrapping_mul(0x2545F4914F6CDD1D);
+            Gf2_128::new(seed)
+        };
+        check_field(gen, 300).unwrap();
+    }
+}
@@ -0,0 +1,886 @@
+//! Prime fields whose modulus is chosen at runtime.
+//!
+//! The modulus and its Montgomery constants live in a [`PrimeFieldContext`], which elements share
+//! through an [`Arc`]. The arithmetic is the same as that of
+//! [`FinitePrimeField`](crate::FinitePrimeField).
+
+use crate::prime_field::{
+    add_mod, mont_mul, mont_pow, neg_inv, pow2_mod, select, shr, small_inverse, sub_mod, sub_small,
+    trailing_zeros,
+};
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, BigUInt, CommutativeAddition,
+    CommutativeMultiplication, ConditionallySelectable, Distributive, DynFiniteField,
+    EuclideanValuation, ExactDivision, NoZeroDivisors, UInt,
+};
+use num_traits::{Euclid, Inv, One, Zero};
+use std::error::Error;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::sync::Arc;
+
+/// The reason a modulus was rejected by [`PrimeFieldContext::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModulusError {
+    /// The modulus is even, which Montgomery arithmetic does not support.
+    Even,
+    /// The modulus is not prime.
+    NotPrime,
+}
+
+impl fmt::Display for ModulusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModulusError::Even => write!(f, "the modulus of a prime field must be odd"),
+            ModulusError::NotPrime => write!(f, "the modulus of a prime field must be prime"),
+        }
+    }
+}
+
+impl Error for ModulusError {}
+
+/// A prime modulus p of `L` limbs together with its precomputed Montgomery constants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrimeFieldContext<const L: usize> {
+    modulus: [u64; L],
+    /// R mod p, where R = 2⁶⁴ᴸ.
+    r: [u64; L],
+    /// R² mod p.
+    r2: [u64; L],
+    /// -p⁻¹ mod 2⁶⁴.
+    inv: u64,
+}
+
+impl<const L: usize> PrimeFieldContext<L> {
+    /// Validates `modulus`, given as little-endian limbs, and precomputes its constants.
+    ///
+    /// Primality is tested with the Miller-Rabin test using the first twelve primes as witnesses.
+    /// The test is exact below 2⁶⁴; above it, a composite modulus is only accepted if it is a
+    /// strong pseudoprime to all twelve bases, which a modulus from an untrusted source could be
+    /// constructed to be.
+    pub fn new(modulus: [u64; L]) -> Result<Arc<Self>, ModulusError> {
+        if modulus[0] & 1 == 0 {
+            return Err(ModulusError::Even);
+        }
+        let context = PrimeFieldContext {
+            modulus,
+            r: pow2_mod(&modulus, 64 * L),
+            r2: pow2_mod(&modulus, 128 * L),
+            inv: neg_inv(modulus[0]),
+        };
+        if !context.is_probable_prime() {
+            return Err(ModulusError::NotPrime);
+        }
+        Ok(Arc::new(context))
+    }
+
+    /// Returns the modulus p as little-endian limbs.
+    pub fn modulus(&self) -> [u64; L] {
+        self.modulus
+    }
+
+    fn to_montgomery(&self, limbs: &[u64; L]) -> [u64; L] {
+        mont_mul(limbs, &self.r2, &self.modulus, self.inv)
+    }
+
+    fn canonical(&self, limbs: &[u64; L]) -> [u64; L] {
+        mont_mul(limbs, &small(1), &self.modulus, self.inv)
+    }
+
+    /// Returns the Montgomery form of the integer `value`.
+    fn integer(&self, value: &Integer) -> [u64; L] {
+        // Horner's rule in base 2⁶⁴.
+        let base = self.to_montgomery(&pow2_mod(&self.modulus, 64));
+        let magnitude = value
+            .magnitude
+            .as_limbs()
+            .iter()
+            .rev()
+            .fold([0; L], |acc, &limb| {
+                add_mod(
+                    &mont_mul(&acc, &base, &self.modulus, self.inv),
+                    &self.to_montgomery(&small(limb)),
+                    &self.modulus,
+                )
+            });
+        if value.negative {
+            sub_mod(&[0; L], &magnitude, &self.modulus)
+        } else {
+            magnitude
+        }
+    }
+
+    fn is_probable_prime(&self) -> bool {
+        const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+        let modulus = &self.modulus;
+        if *modulus == small(1) {
+            return false;
+        }
+        for &p in &WITNESSES {
+            let remainder = modulus
+                .iter()
+                .rev()
+                .fold(0u128, |acc, &limb| ((acc << 64) | limb as u128) % p as u128);
+            if remainder == 0 {
+                return *modulus == small(p);
+            }
+        }
+
+        // p - 1 = d · 2ˢ with d odd. The witnesses are all below p, which exceeds 37 here.
+        let minus_one = sub_small(modulus, 1);
+        let s = trailing_zeros(&minus_one);
+        let d = shr(&minus_one, s);
+        let minus_one = sub_mod(&[0; L], &self.r, modulus);
+        'witness: for &a in &WITNESSES {
+            let mut x = mont_pow(
+                &self.to_montgomery(&small(a)),
+                &d,
+                &self.r,
+                modulus,
+                self.inv,
+            );
+            if x == self.r || x == minus_one {
+                continue;
+            }
+            for _ in 1..s {
+                x = mont_mul(&x, &x, modulus, self.inv);
+                if x == minus_one {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+        true
+    }
+}
+
+/// Returns the `L`-limb integer `value`.
+fn small<const L: usize>(value: u64) -> [u64; L] {
+    let mut limbs = [0; L];
+    limbs[0] = value;
+    limbs
+}
+
+/// An integer of arbitrary precision, so that arithmetic without a modulus never overflows.
+#[derive(Clone, Default, PartialEq, Eq)]
+struct Integer {
+    /// Whether the integer is negative, which zero never is.
+    negative: bool,
+    magnitude: BigUInt,
+}
+
+impl Integer {
+    fn new(negative: bool, magnitude: BigUInt) -> Self {
+        Integer {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    fn from_i64(value: i64) -> Self {
+        Self::new(value < 0, BigUInt::from(value.unsigned_abs()))
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        if self.negative == rhs.negative {
+            return Self::new(self.negative, &self.magnitude + &rhs.magnitude);
+        }
+        match self.magnitude.checked_sub(&rhs.magnitude) {
+            Some(difference) => Self::new(self.negative, difference),
+            None => Self::new(
+                rhs.negative,
+                rhs.magnitude.checked_sub(&self.magnitude).unwrap(),
+            ),
+        }
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+
+    fn neg(&self) -> Self {
+        Self::new(!self.negative, self.magnitude.clone())
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.negative != rhs.negative,
+            &self.magnitude * &rhs.magnitude,
+        )
+    }
+}
+
+impl fmt::Display for Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.magnitude)
+    }
+}
+
+#[derive(Clone)]
+enum Repr<const L: usize> {
+    /// An integer not yet bound to a modulus.
+    Integer(Integer),
+    /// A residue in Montgomery form.
+    Residue {
+        context: Arc<PrimeFieldContext<L>>,
+        limbs: [u64; L],
+    },
+}
+
+/// An element of the prime field 𝔽ₚ, where p is chosen at runtime and fits in `L` 64-bit limbs.
+///
+/// # Mathematical Definition
+/// 𝔽ₚ is the set {0, 1, ..., p - 1} with addition and multiplication modulo the prime p.
+///
+/// Elements created from a [`PrimeFieldContext`] carry a reference to it. Elements created
+/// without one, by [`Zero::zero`], [`One::one`] or [`From<i64>`], are integers: elements of ℤ,
+/// whose image lies in every prime field. Arithmetic between them is exact, with arbitrary
+/// precision, and they are reduced when they meet an element with a context.
+///
+/// An integer without a context is compared as an integer: it is zero only if it is 0, and two
+/// of them are equal only if they are the same integer, so 101 is neither zero nor equal to 0
+/// until it is bound to a field. When one operand of a comparison has a context, the other is
+/// reduced in that context first, so 101 equals the zero of 𝔽₁₀₁.
+///
+/// # Panics
+/// Combining elements whose contexts have different moduli is a logic error, and the operators
+/// panic when it happens. [`DynPrimeField::context`] can be used to check elements beforehand.
+/// Equality does not panic on different moduli: elements of different fields are unequal.
+///
+/// Inverting an integer other than ±1 without a context panics, since its inverse depends on
+/// the modulus.
+#[derive(Clone)]
+pub struct DynPrimeField<const L: usize>(Repr<L>);
+
+impl<const L: usize> DynPrimeField<L> {
+    /// Creates the residue class of the little-endian integer `limbs` modulo the context's p.
+    pub fn from_limbs(context: &Arc<PrimeFieldContext<L>>, limbs: [u64; L]) -> Self {
+        DynPrimeField(Repr::Residue {
+            limbs: context.to_montgomery(&limbs),
+            context: Arc::clone(context),
+        })
+    }
+
+    /// Creates the residue class of `value` modulo the context's p.
+    pub fn from_u64(context: &Arc<PrimeFieldContext<L>>, value: u64) -> Self {
+        Self::from_limbs(context, small(value))
+    }
+
+    /// Creates the residue class of the signed `value` modulo the context's p.
+    pub fn from_i64(context: &Arc<PrimeFieldContext<L>>, value: i64) -> Self {
+        DynPrimeField(Repr::Residue {
+            limbs: context.integer(&Integer::from_i64(value)),
+            context: Arc::clone(context),
+        })
+    }
+
+    /// Returns the context of the element, or `None` if it is an integer not yet bound to one.
+    pub fn context(&self) -> Option<&Arc<PrimeFieldContext<L>>> {
+        match &self.0 {
+            Repr::Integer(_) => None,
+            Repr::Residue { context, .. } => Some(context),
+        }
+    }
+
+    /// Returns the canonical representative of the element, in `0..p`, as little-endian limbs,
+    /// or `None` if the element has no context.
+    pub fn to_limbs(&self) -> Option<[u64; L]> {
+        match &self.0 {
+            Repr::Integer(_) => None,
+            Repr::Residue { context, limbs } => Some(context.canonical(limbs)),
+        }
+    }
+
+    /// Returns the element bound to the context of `other`, if `self` is an integer without a
+    /// context and `other` has one.
+    fn bound_like(self, other: &Self) -> Self {
+        match (self.0, other.context()) {
+            (Repr::Integer(value), Some(context)) => DynPrimeField(Repr::Residue {
+                limbs: context.integer(&value),
+                context: Arc::clone(context),
+            }),
+            (repr, _) => DynPrimeField(repr),
+        }
+    }
+
+    /// Returns the Montgomery form of the element in `context`.
+    fn residue(&self, context: &PrimeFieldContext<L>) -> [u64; L] {
+        match &self.0 {
+            Repr::Integer(value) => context.integer(value),
+            Repr::Residue { limbs, .. } => *limbs,
+        }
+    }
+
+    /// Returns the context shared by two elements, at least one of which has a context.
+    fn common_context(&self, other: &Self) -> Arc<PrimeFieldContext<L>> {
+        match (&self.0, &other.0) {
+            (Repr::Residue { context: a, .. }, Repr::Residue { context: b, .. }) => {
+                assert!(
+                    compatible(a, b),
+                    "cannot combine elements of different prime fields"
+                );
+                Arc::clone(a)
+            }
+            (Repr::Residue { context, .. }, _) | (_, Repr::Residue { context, .. }) => {
+                Arc::clone(context)
+            }
+            _ => unreachable!("neither element has a context"),
+        }
+    }
+
+    fn binary(
+        self,
+        rhs: Self,
+        integer: fn(&Integer, &Integer) -> Integer,
+        residue: fn(&PrimeFieldContext<L>, &[u64; L], &[u64; L]) -> [u64; L],
+    ) -> Self {
+        if let (Repr::Integer(a), Repr::Integer(b)) = (&self.0, &rhs.0) {
+            return DynPrimeField(Repr::Integer(integer(a, b)));
+        }
+        let context = self.common_context(&rhs);
+        let limbs = residue(&context, &self.residue(&context), &rhs.residue(&context));
+        DynPrimeField(Repr::Residue { context, limbs })
+    }
+}
+
+fn compatible<const L: usize>(
+    a: &Arc<PrimeFieldContext<L>>,
+    b: &Arc<PrimeFieldContext<L>>,
+) -> bool {
+    Arc::ptr_eq(a, b) || a.modulus == b.modulus
+}
+
+impl<const L: usize> PartialEq for DynPrimeField<L> {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Repr::Integer(a), Repr::Integer(b)) => a == b,
+            (Repr::Residue { context: a, .. }, Repr::Residue { context: b, .. })
+                if !compatible(a, b) =>
+            {
+                false
+            }
+            _ => {
+                let context = self.common_context(other);
+                self.residue(&context)
+                    .iter()
+                    .zip(&other.residue(&context))
+                    .fold(0, |acc, (a, b)| acc | (a ^ b))
+                    == 0
+            }
+        }
+    }
+}
+
+impl<const L: usize> fmt::Debug for DynPrimeField<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DynPrimeField({self})")
+    }
+}
+
+impl<const L: usize> fmt::Display for DynPrimeField<L> {
+    /// Formats a residue in hexadecimal, and an integer without a context in decimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Repr::Integer(value) => write!(f, "{value}"),
+            Repr::Residue { context, limbs } => {
+                write!(f, "0x")?;
+                for limb in context.canonical(limbs).iter().rev() {
+                    write!(f, "{limb:016x}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<const L: usize> Default for DynPrimeField<L> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const L: usize> From<i64> for DynPrimeField<L> {
+    fn from(value: i64) -> Self {
+        DynPrimeField(Repr::Integer(Integer::from_i64(value)))
+    }
+}
+
+impl<const L: usize> Add for DynPrimeField<L> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.binary(rhs, Integer::add, |context, a, b| {
+            add_mod(a, b, &context.modulus)
+        })
+    }
+}
+
+impl<const L: usize> Sub for DynPrimeField<L> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.binary(rhs, Integer::sub, |context, a, b| {
+            sub_mod(a, b, &context.modulus)
+        })
+    }
+}
+
+impl<const L: usize> Neg for DynPrimeField<L> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self.0 {
+            Repr::Integer(value) => DynPrimeField(Repr::Integer(value.neg())),
+            Repr::Residue { context, limbs } => DynPrimeField(Repr::Residue {
+                limbs: sub_mod(&[0; L], &limbs, &context.modulus),
+                context,
+            }),
+        }
+    }
+}
+
+impl<const L: usize> Mul for DynPrimeField<L> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.binary(rhs, Integer::mul, |context, a, b| {
+            mont_mul(a, b, &context.modulus, context.inv)
+        })
+    }
+}
+
+impl<const L: usize> Inv for DynPrimeField<L> {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse a⁻¹ = aᵖ⁻².
+    ///
+    /// # Panics
+    /// Panics if `self` is zero, or is an integer other than ±1 without a context, whose inverse
+    /// depends on the modulus.
+    fn inv(self) -> Self {
+        assert!(!self.is_zero(), "attempt to invert zero in DynPrimeField");
+        match self.0 {
+            Repr::Integer(value) => {
+                assert!(
+                    value.magnitude.is_one(),
+                    "the inverse of the integer {value} depends on the modulus, which it does not have"
+                );
+                DynPrimeField(Repr::Integer(value))
+            }
+            Repr::Residue { context, limbs } => {
+                let exponent = sub_small(&context.modulus, 2);
+                DynPrimeField(Repr::Residue {
+                    limbs: mont_pow(&limbs, &exponent, &context.r, &context.modulus, context.inv),
+                    context,
+                })
+            }
+        }
+    }
+}
+
+impl<const L: usize> ConditionallySelectable for DynPrimeField<L> {
+    /// Selects between two integers without a context, and otherwise between the residues of
+    /// both in their common context. Only that distinction, not `choice`, picks the branch.
+    ///
+    /// # Panics
+    /// Panics if the elements belong to different prime fields.
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        if let (Repr::Integer(x), Repr::Integer(y)) = (&a.0, &b.0) {
+            let mask = 0u64.wrapping_sub(choice);
+            let (xs, ys) = (x.magnitude.as_limbs(), y.magnitude.as_limbs());
+            let limbs: Vec<u64> = (0..xs.len().max(ys.len()))
+                .map(|i| {
+                    let (x, y) = (xs.get(i).unwrap_or(&0), ys.get(i).unwrap_or(&0));
+                    (x & !mask) | (y & mask)
+                })
+                .collect();
+            let negative = ((x.negative as u64 & !mask) | (y.negative as u64 & mask)) != 0;
+            return DynPrimeField(Repr::Integer(Integer::new(
+                negative,
+                BigUInt::from_limbs(&limbs),
+            )));
+        }
+        let context = a.common_context(b);
+        let limbs = select(&a.residue(&context), &b.residue(&context), choice);
+        DynPrimeField(Repr::Residue { context, limbs })
+    }
+}
+
+impl<const L: usize> ExactDivision for DynPrimeField<L> {
+    /// Multiplies by the inverse of n, which exists unless p divides n.
+    ///
+    /// An integer without a context is divided in ℤ, and only when n divides it there: then
+    /// n · (v / n) = v holds in every field, while any other quotient would depend on the
+    /// modulus.
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        match &self.0 {
+            Repr::Integer(value) => {
+                if n == 0 {
+                    return None;
+                }
+                let (quotient, remainder) = value.magnitude.div_rem_u64(u64::from(n));
+                (remainder == 0)
+                    .then(|| DynPrimeField(Repr::Integer(Integer::new(value.negative, quotient))))
+            }
+            Repr::Residue { context, .. } => small_inverse(n as u64, &context.modulus)
+                .map(|inverse| self.clone() * Self::from_limbs(context, inverse)),
+        }
+    }
+}
+
+impl<const L: usize> Div for DynPrimeField<L> {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        // Bind an integer divisor first, so that it is inverted in the field of `self`.
+        let rhs = rhs.bound_like(&self);
+        self * rhs.inv()
+    }
+}
+
+impl<const L: usize> Rem for DynPrimeField<L> {
+    type Output = Self;
+
+    /// Returns the remainder of field division, which is always zero.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            !rhs.bound_like(&self).is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        Self::zero()
+    }
+}
+
+impl<const L: usize> AddAssign for DynPrimeField<L> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) + rhs;
+    }
+}
+
+impl<const L: usize> SubAssign for DynPrimeField<L> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) - rhs;
+    }
+}
+
+impl<const L: usize> MulAssign for DynPrimeField<L> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) * rhs;
+    }
+}
+
+impl<const L: usize> DivAssign for DynPrimeField<L> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) / rhs;
+    }
+}
+
+impl<const L: usize> RemAssign for DynPrimeField<L> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = std::mem::take(self) % rhs;
+    }
+}
+
+impl<const L: usize> Zero for DynPrimeField<L> {
+    fn zero() -> Self {
+        DynPrimeField(Repr::Integer(Integer::default()))
+    }
+
+    /// Returns whether the element is zero, which an integer without a context is only if it is
+    /// the integer 0.
+    fn is_zero(&self) -> bool {
+        match &self.0 {
+            Repr::Integer(value) => value.magnitude.is_zero(),
+            Repr::Residue { limbs, .. } => limbs.iter().fold(0, |acc, limb| acc | limb) == 0,
+        }
+    }
+}
+
+impl<const L: usize> One for DynPrimeField<L> {
+    fn one() -> Self {
+        DynPrimeField(Repr::Integer(Integer::from_i64(1)))
+    }
+}
+
+impl<const L: usize> Euclid for DynPrimeField<L> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.clone() / v.clone()
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.clone() % v.clone()
+    }
+}
+
+impl<const L: usize> EuclideanValuation for DynPrimeField<L> {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(!self.is_zero())
+    }
+}
+
+impl<const L: usize> CommutativeAddition for DynPrimeField<L> {}
+impl<const L: usize> AssociativeAddition for DynPrimeField<L> {}
+impl<const L: usize> CommutativeMultiplication for DynPrimeField<L> {}
+impl<const L: usize> AssociativeMultiplication for DynPrimeField<L> {}
+impl<const L: usize> Distributive for DynPrimeField<L> {}
+impl<const L: usize> NoZeroDivisors for DynPrimeField<L> {}
+
+impl<const L: usize> DynFiniteField for DynPrimeField<L> {
+    type ScalarType = UInt<L>;
+
+    /// Returns the modulus p.
+    ///
+    /// # Panics
+    /// Panics if the element has no context.
+    fn field_characteristic(&self) -> UInt<L> {
+        let context = self
+            .context()
+            .expect("an element without a context does not determine its field");
+        UInt::from_limbs(context.modulus)
+    }
+
+    /// Returns the number of elements p.
+    ///
+    /// # Panics
+    /// Panics if the element has no context.
+    fn field_order(&self) -> UInt<L> {
+        self.field_characteristic()
+    }
+
+    fn field_extension_degree(&self) -> u32 {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_field;
+
+    const BN254: [u64; 4] = [
+        0x3C208C16D87CFD47,
+        0x97816A916871CA8D,
+        0xB85045B68181585D,
+        0x30644E72E131A029,
+    ];
+
+    fn f101() -> Arc<PrimeFieldContext<1>> {
+        PrimeFieldContext::new([101]).unwrap()
+    }
+
+    #[test]
+    fn contexts_validate_the_modulus() {
+        assert_eq!(PrimeFieldContext::new([100]), Err(ModulusError::Even));
+        assert_eq!(PrimeFieldContext::new([1]), Err(ModulusError::NotPrime));
+        assert_eq!(PrimeFieldContext::new([561]), Err(ModulusError::NotPrime));
+        assert_eq!(
+            PrimeFieldContext::new([3_825_123_056_546_413_051]),
+            Err(ModulusError::NotPrime)
+        );
+        assert_eq!(PrimeFieldContext::new([3]).unwrap().modulus(), [3]);
+        assert_eq!(PrimeFieldContext::new(BN254).unwrap().modulus(), BN254);
+        // p² for the BN254 modulus p has no small factor but fails Miller-Rabin.
+        let mut square = [0u64; 8];
+        for (i, &a) in BN254.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in BN254.iter().enumerate() {
+                let t = square[i + j] as u128 + a as u128 * b as u128 + carry;
+                square[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            square[i + 4] = carry as u64;
+        }
+        assert_eq!(PrimeFieldContext::new(square), Err(ModulusError::NotPrime));
+    }
+
+    #[test]
+    fn arithmetic_modulo_a_runtime_prime() {
+        let context = f101();
+        let a = DynPrimeField::from_u64(&context, 57);
+        let b = DynPrimeField::from_u64(&context, 73);
+        assert_eq!((a.clone() + b.clone()).to_limbs(), Some([29]));
+        assert_eq!((a.clone() - b.clone()).to_limbs(), Some([85]));
+        assert_eq!((a.clone() * b.clone()).to_limbs(), Some([20]));
+        assert_eq!((-a.clone()).to_limbs(), Some([44]));
+        assert_eq!(a.clone().inv().to_limbs(), Some([39]));
+        assert_eq!((a.clone() / b.clone() * b).to_limbs(), Some([57]));
+        assert_eq!(
+            DynPrimeField::from_i64(&context, -1).to_limbs(),
+            Some([100])
+        );
+        assert_eq!(
+            DynPrimeField::from_limbs(&context, [202]).to_limbs(),
+            Some([0])
+        );
+        assert_eq!(a.to_string(), "0x0000000000000039");
+        assert_eq!(a.field_characteristic(), UInt::from(101));
+        assert_eq!(a.field_order(), UInt::from(101));
+        assert_eq!(a.field_extension_degree(), 1);
+    }
+
+    #[test]
+    fn field_laws() {
+        let context = f101();
+        let mut state = 0u64;
+        check_field(
+            || {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                DynPrimeField::from_u64(&context, state >> 32)
+            },
+            500,
+        )
+        .unwrap();
+
+        let context = PrimeFieldContext::new(BN254).unwrap();
+        let mut state = 0x2545F4914F6CDD1Du64;
+        check_field(
+            || {
+                let mut limbs = [0; 4];
+                for limb in &mut limbs {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    *limb = state;
+                }
+                DynPrimeField::from_limbs(&context, limbs)
+            },
+            200,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn integers_are_bound_when_they_meet_a_context() {
+        let context = f101();
+        let x = DynPrimeField::from_u64(&context, 5);
+        assert_eq!(
+            DynPrimeField::from(101),
+            DynPrimeField::from_u64(&context, 0)
+        );
+        assert_eq!((DynPrimeField::from(100) + x.clone()).to_limbs(), Some([4]));
+        assert_eq!((x.clone() / DynPrimeField::from(2)).to_limbs(), Some([53]));
+        assert_eq!((DynPrimeField::from(3) / x).to_limbs(), Some([41]));
+    }
+
+    #[test]
+    fn integers_without_a_context_compare_as_integers() {
+        type F = DynPrimeField<1>;
+        assert!(F::zero().is_zero());
+        assert!(!F::one().is_zero());
+        assert!(!F::from(-64).is_zero());
+        assert!(!(F::one() + F::one() + F::one()).is_zero());
+        assert_eq!(F::one() + F::one() + F::one(), F::from(3));
+        assert_eq!(F::from(7), F::from(7));
+        assert_ne!(F::from(101), F::zero());
+        assert_ne!(F::from(7), F::from(3));
+        assert!((F::from(-5) + F::from(5)).is_zero());
+        assert_eq!(F::one().inv(), F::one());
+        assert_eq!((-F::one()).inv(), -F::one());
+        // Against an element with a context, the integer is reduced first.
+        assert_eq!(F::from(101), DynPrimeField::from_u64(&f101(), 0));
+        assert_eq!(DynPrimeField::from_u64(&f101(), 3), F::from(104 + 101));
+        assert_eq!(F::from(-1), DynPrimeField::from_u64(&f101(), 100));
+    }
+
+    #[test]
+    fn integer_arithmetic_without_a_context_does_not_overflow() {
+        type F = DynPrimeField<1>;
+        let big = F::from(i64::MAX) * F::from(i64::MAX) * F::from(i64::MIN);
+        assert_ne!(big, F::zero());
+        assert_eq!(
+            big.to_string(),
+            "-784637716923335095309332494440489070290330498878974984192"
+        );
+        assert!((big.clone() - big.clone()).is_zero());
+        assert_eq!(-F::from(i64::MIN) - F::one(), F::from(i64::MAX));
+        assert_eq!(big.div_exact(2).unwrap().div_exact(3), None);
+
+        // (2⁶³ - 1)² · (-2⁶³) mod 101, computed with i128 arithmetic.
+        let context = f101();
+        let expected = (i64::MAX as i128 % 101).pow(2) * (i64::MIN as i128 % 101);
+        assert_eq!(
+            big + DynPrimeField::from_u64(&context, 0),
+            DynPrimeField::from_i64(&context, (expected % 101) as i64)
+        );
+    }
+
+    #[test]
+    fn dense_polynomials_keep_integer_coefficients() {
+        use crate::polynomial::DensePoly;
+        type F = DynPrimeField<1>;
+        let three = DensePoly::new(vec![F::from(3)]);
+        assert_eq!(three.coefficients(), &[F::from(3)]);
+        assert!(DensePoly::new(vec![F::zero(), F::one() - F::one()]).is_zero());
+
+        // 3x² + 2x + 1, with integer coefficients, times x - 5 over 𝔽₁₀₁.
+        let context = f101();
+        let a = DensePoly::new(vec![F::from(1), F::from(2), F::from(3)]);
+        let b = DensePoly::new(vec![DynPrimeField::from_i64(&context, -5), F::one()]);
+        let product = a.clone() * b.clone();
+        let expected = [-5, -9, -13, 3].map(|c| DynPrimeField::from_i64(&context, c));
+        assert_eq!(product.coefficients(), &expected);
+
+        let (quotient, remainder) = product.div_rem(&b);
+        assert_eq!(quotient, a);
+        assert!(remainder.is_zero());
+        let x = DynPrimeField::from_u64(&context, 5);
+        assert!(product.evaluate(&x).is_zero());
+        // 101 vanishes once it meets the field.
+        let vanishing = DensePoly::new(vec![F::from(101), F::one()]);
+        assert_eq!(vanishing.evaluate(&x), x);
+    }
+
+    #[test]
+    fn exact_division_binds_only_when_the_field_matters() {
+        type F = DynPrimeField<1>;
+        // -6 / 3 = -2 in ℤ, hence in every field.
+        assert_eq!(F::from(-6).div_exact(3), Some(F::from(-2)));
+        assert_eq!(F::from(1).div_exact(2), None);
+        assert_eq!(F::from(1).div_exact(0), None);
+
+        let x = DynPrimeField::from_u64(&f101(), 1);
+        assert_eq!(x.div_exact(2).unwrap().to_limbs(), Some([51]));
+        assert_eq!(x.div_exact(3).unwrap().to_limbs(), Some([34]));
+        assert_eq!(x.div_exact(101), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "the inverse of the integer 2 depends on the modulus")]
+    fn integers_other_than_units_are_not_inverted() {
+        let _ = DynPrimeField::<1>::from(2).inv();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn integer_divisors_are_bound_before_the_zero_check() {
+        let _ = DynPrimeField::from_u64(&f101(), 5) % DynPrimeField::from(101);
+    }
+
+    #[test]
+    fn elements_of_different_fields_are_unequal() {
+        let a = DynPrimeField::from_u64(&f101(), 5);
+        let b = DynPrimeField::from_u64(&PrimeFieldContext::new([103]).unwrap(), 5);
+        assert_ne!(a, b);
+        // Contexts with the same modulus are interchangeable.
+        assert_eq!(a, DynPrimeField::from_u64(&f101(), 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot combine elements of different prime fields")]
+    fn combining_different_fields_panics() {
+        let a = DynPrimeField::from_u64(&f101(), 5);
+        let b = DynPrimeField::from_u64(&PrimeFieldContext::new([103]).unwrap(), 5);
+        let _ = a + b;
+    }
+}
@@ -0,0 +1,357 @@
+//! Algorithms on Euclidean domains.
+//!
+//! Division with remainder is all the Euclidean algorithm needs, so the functions in this module
+//! work for every [`EuclideanDomain`]: the integers through [`Checked`](crate::Checked), fields
+//! such as [`Fp`](crate::Fp), and polynomials over a field.
+//!
+//! A greatest common divisor is only unique up to multiplication by a unit. The functions return
+//! the one the Euclidean algorithm reaches, without normalizing it: over the integers it may be
+//! negative, and over a field it is the last non-zero argument. Polynomial results can be made
+//! monic with [`DensePoly::monic`](crate::DensePoly::monic).
+//!
+//! [`crt`] and [`CrtBasis`] solve systems of congruences modulo pairwise coprime moduli with the
+//! Chinese Remainder Theorem.
+//!
+//! ```
+//! use noether::euclidean::{extended_gcd, gcd, lcm};
+//! use noether::Checked;
+//!
+//! let (a, b) = (Checked(240i64), Checked(46i64));
+//! assert_eq!(gcd(a, b), Checked(2));
+//! assert_eq!(lcm(a, b), Checked(5520));
+//!
+//! let (g, s, t) = extended_gcd(a, b);
+//! assert_eq!(s * a + t * b, g);
+//! ```
+
+use crate::EuclideanDomain;
+use num_traits::Euclid;
+
+/// Returns a greatest common divisor of `a` and `b`: a common divisor which every common divisor
+/// divides.
+///
+/// gcd(a, 0) = a, and gcd(0, 0) = 0.
+pub fn gcd<R: EuclideanDomain>(mut a: R, mut b: R) -> R {
+    while !b.is_zero() {
+        let r = Euclid::rem_euclid(&a, &b);
+        a = std::mem::replace(&mut b, r);
+    }
+    a
+}
+
+/// Returns a least common multiple of `a` and `b`: a common multiple which divides every common
+/// multiple.
+///
+/// The least common multiple is zero when either operand is.
+pub fn lcm<R: EuclideanDomain>(a: R, b: R) -> R {
+    if a.is_zero() || b.is_zero() {
+        return R::zero();
+    }
+    let g = gcd(a.clone(), b.clone());
+    Euclid::div_euclid(&a, &g) * b
+}
+
+/// Returns a greatest common divisor g of `a` and `b` together with Bézout coefficients s and t
+/// such that s·a + t·b = g, as the tuple `(g, s, t)`.
+///
+/// The divisor g is the one returned by [`gcd`]. When g is a unit, g⁻¹·s is the inverse of `a`
+/// modulo `b`.
+///
+/// # Example
+/// ```
+/// use noether::euclidean::extended_gcd;
+/// use noether::{DensePoly, Fp};
+///
+/// type P = DensePoly<Fp<7>>;
+///
+/// // (x - 1)(x - 2) and (x - 1)(x + 3) share the factor x - 1.
+/// let a = P::new(vec![Fp::new(2), Fp::new(4), Fp::new(1)]);
+/// let b = P::new(vec![Fp::new(4), Fp::new(2), Fp::new(1)]);
+/// let (g, s, t) = extended_gcd(a.clone(), b.clone());
+/// assert_eq!(g.monic(), P::new(vec![Fp::new(6), Fp::new(1)]));
+/// assert_eq!(s * a + t * b, g);
+/// ```
+pub fn extended_gcd<R: EuclideanDomain>(a: R, b: R) -> (R, R, R) {
+    // Invariants: s0·a + t0·b = r0 and s1·a + t1·b = r1.
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (R::one(), R::zero());
+    let (mut t0, mut t1) = (R::zero(), R::one());
+    while !r1.is_zero() {
+        let q = Euclid::div_euclid(&r0, &r1);
+        let r = r0 - q.clone() * r1.clone();
+        let s = s0 - q.clone() * s1.clone();
+        let t = t0 - q * t1.clone();
+        r0 = std::mem::replace(&mut r1, r);
+        s0 = std::mem::replace(&mut s1, s);
+        t0 = std::mem::replace(&mut t1, t);
+    }
+    (r0, s0, t0)
+}
+
+/// Returns whether `a` is a unit, that is whether it divides 1.
+fn is_unit<R: EuclideanDomain>(a: &R) -> bool {
+    !a.is_zero() && Euclid::rem_euclid(&R::one(), a).is_zero()
+}
+
+/// Returns the inverse of `a` modulo `m`, or `None` if `a` and `m` are not coprime.
+fn inverse_mod<R: EuclideanDomain>(a: &R, m: &R) -> Option<R> {
+    let (g, s, _) = extended_gcd(a.clone(), m.clone());
+    // s·a ≡ g (mod m), and g is a unit exactly when a and m are coprime.
+    is_unit(&g).then(|| Euclid::rem_euclid(&(s * Euclid::div_euclid(&R::one(), &g)), m))
+}
+
+/// Precomputed data for the Chinese Remainder Theorem over a fixed list of moduli.
+///
+/// # Mathematical Definition
+/// For pairwise coprime m₀, ..., mₖ₋₁ with product M, the Chinese Remainder Theorem gives the
+/// ring isomorphism
+///
+/// R/(M) ≅ R/(m₀) × ... × R/(mₖ₋₁)
+///
+/// whose inverse maps the residues (r₀, ..., rₖ₋₁) to x = Σᵢ rᵢ · cᵢ · M/mᵢ mod M, where cᵢ is
+/// the inverse of M/mᵢ modulo mᵢ. The basis stores M/mᵢ and cᵢ, so that each reconstruction
+/// only needs multiplications and reductions.
+///
+/// Over the integers this is the residue number system; over polynomials, reconstructing from
+/// the residues modulo x - aᵢ is Lagrange interpolation.
+///
+/// # Example
+/// ```
+/// use noether::euclidean::CrtBasis;
+/// use noether::{DensePoly, Fp};
+///
+/// type F = Fp<101>;
+/// type P = DensePoly<F>;
+///
+/// // The residues of a polynomial modulo x - a are its values at a.
+/// let points = [F::new(1), F::new(2), F::new(3)];
+/// let moduli: Vec<P> = points.iter().map(|&a| P::x() - P::from(a)).collect();
+/// let basis = CrtBasis::new(&moduli).unwrap();
+///
+/// let p = P::new(vec![F::new(5), F::new(0), F::new(1)]);
+/// let values: Vec<P> = points.iter().map(|a| P::from(p.evaluate(a))).collect();
+/// assert_eq!(basis.reconstruct(&values), p);
+/// assert_eq!(basis.residues(&p), values);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CrtBasis<R> {
+    moduli: Vec<R>,
+    modulus: R,
+    cofactors: Vec<R>,
+    inverses: Vec<R>,
+}
+
+impl<R: EuclideanDomain> CrtBasis<R> {
+    /// Precomputes the reconstruction for `moduli`.
+    ///
+    /// Returns `None` if the moduli are not pairwise coprime, or if one of them is zero.
+    pub fn new(moduli: &[R]) -> Option<Self> {
+        if moduli.iter().any(R::is_zero) {
+            return None;
+        }
+        let modulus = moduli
+            .iter()
+            .fold(R::one(), |product, m| product * m.clone());
+        let cofactors: Vec<R> = moduli
+            .iter()
+            .map(|m| Euclid::div_euclid(&modulus, m))
+            .collect();
+        // M/mᵢ is invertible modulo mᵢ exactly when mᵢ is coprime to every other modulus.
+        let inverses = moduli
+            .iter()
+            .zip(&cofactors)
+            .map(|(m, cofactor)| inverse_mod(&Euclid::rem_euclid(cofactor, m), m))
+            .collect::<Option<Vec<R>>>()?;
+        Some(CrtBasis {
+            moduli: moduli.to_vec(),
+            modulus,
+            cofactors,
+            inverses,
+        })
+    }
+
+    /// Returns the moduli m₀, ..., mₖ₋₁.
+    pub fn moduli(&self) -> &[R] {
+        &self.moduli
+    }
+
+    /// Returns the product M of the moduli.
+    pub fn modulus(&self) -> &R {
+        &self.modulus
+    }
+
+    /// Returns the unique x modulo M with x ≡ rᵢ (mod mᵢ) for every residue rᵢ in `residues`,
+    /// reduced by `rem_euclid`.
+    ///
+    /// # Panics
+    /// Panics if the number of residues differs from the number of moduli.
+    pub fn reconstruct(&self, residues: &[R]) -> R {
+        assert_eq!(
+            residues.len(),
+            self.moduli.len(),
+            "expected one residue per modulus"
+        );
+        // Each term is reduced below M, which keeps bounded types such as `Checked` from
+        // overflowing while M itself fits.
+        let mut x = R::zero();
+        for (((r, m), cofactor), inverse) in residues
+            .iter()
+            .zip(&self.moduli)
+            .zip(&self.cofactors)
+            .zip(&self.inverses)
+        {
+            let digit = Euclid::rem_euclid(&(Euclid::rem_euclid(r, m) * inverse.clone()), m);
+            x = Euclid::rem_euclid(&(x + digit * cofactor.clone()), &self.modulus);
+        }
+        x
+    }
+
+    /// Returns the residues of `x` modulo each of the moduli, the inverse of
+    /// [`reconstruct`](Self::reconstruct).
+    pub fn residues(&self, x: &R) -> Vec<R> {
+        self.moduli
+            .iter()
+            .map(|m| Euclid::rem_euclid(x, m))
+            .collect()
+    }
+}
+
+/// Returns the unique x modulo the product of `moduli` with x ≡ `residues[i]` (mod `moduli[i]`)
+/// for every i, or `None` if the moduli are not pairwise coprime or one of them is zero.
+///
+/// To solve many systems over the same moduli, precompute a [`CrtBasis`] instead.
+///
+/// # Panics
+/// Panics if the number of residues differs from the number of moduli.
+///
+/// # Example
+/// ```
+/// use noether::euclidean::crt;
+/// use noether::Checked;
+///
+/// // x ≡ 2 (mod 3), x ≡ 3 (mod 5) and x ≡ 2 (mod 7).
+/// let residues = [Checked(2i64), Checked(3), Checked(2)];
+/// let moduli = [Checked(3i64), Checked(5), Checked(7)];
+/// assert_eq!(crt(&residues, &moduli), Some(Checked(23)));
+///
+/// assert_eq!(crt(&[Checked(1i64), Checked(2)], &[Checked(4), Checked(6)]), None);
+/// ```
+pub fn crt<R: EuclideanDomain>(residues: &[R], moduli: &[R]) -> Option<R> {
+    assert_eq!(
+        residues.len(),
+        moduli.len(),
+        "expected one residue per modulus"
+    );
+    CrtBasis::new(moduli).map(|basis| basis.reconstruct(residues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Checked;
+    use num_traits::Zero;
+
+    /// The gcd of |a| and |b| by the subtractive definition, as a reference.
+    fn reference_gcd(a: i64, b: i64) -> i64 {
+        let (mut a, mut b) = (a.abs(), b.abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    #[test]
+    fn bezout_coefficients_with_negative_integers() {
+        let values: [i64; 13] = [
+            -240,
+            -46,
+            -17,
+            -12,
+            -1,
+            0,
+            1,
+            7,
+            12,
+            46,
+            240,
+            1 << 30,
+            -(1 << 30) + 3,
+        ];
+        for &a in &values {
+            for &b in &values {
+                let (g, s, t) = extended_gcd(Checked(a), Checked(b));
+                assert_eq!(s * Checked(a) + t * Checked(b), g, "a = {a}, b = {b}");
+                assert_eq!(g.0.abs(), reference_gcd(a, b), "a = {a}, b = {b}");
+                assert_eq!(gcd(Checked(a), Checked(b)), g);
+                if !g.is_zero() {
+                    assert!(Euclid::rem_euclid(&Checked(a), &g).is_zero());
+                    assert!(Euclid::rem_euclid(&Checked(b), &g).is_zero());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_modulo_a_negative_integer() {
+        let (g, s, t) = extended_gcd(Checked(-17i64), Checked(-240i64));
+        assert!(is_unit(&g));
+        assert_eq!(s * Checked(-17) + t * Checked(-240), g);
+        let inverse = inverse_mod(&Checked(-17i64), &Checked(240i64)).unwrap();
+        assert_eq!(
+            Euclid::rem_euclid(&(inverse * Checked(-17)), &Checked(240)),
+            Checked(1)
+        );
+        assert_eq!(inverse_mod(&Checked(-12i64), &Checked(46i64)), None);
+    }
+
+    #[test]
+    fn lcm_of_negative_integers() {
+        assert_eq!(lcm(Checked(-4i64), Checked(6i64)).0.abs(), 12);
+        assert_eq!(lcm(Checked(-4i64), Checked(-6i64)).0.abs(), 12);
+        assert_eq!(lcm(Checked(-4i64), Checked(0i64)), Checked(0));
+    }
+
+    #[test]
+    fn crt_basis_rejects_non_coprime_and_zero_moduli() {
+        assert_eq!(CrtBasis::new(&[Checked(4i64), Checked(6)]), None);
+        assert_eq!(
+            CrtBasis::new(&[Checked(3i64), Checked(5), Checked(21)]),
+            None
+        );
+        assert_eq!(CrtBasis::new(&[Checked(-9i64), Checked(6)]), None);
+        assert_eq!(CrtBasis::new(&[Checked(3i64), Checked(0)]), None);
+        assert_eq!(CrtBasis::new(&[Checked(0i64)]), None);
+        assert_eq!(
+            crt(&[Checked(1i64), Checked(1)], &[Checked(0), Checked(5)]),
+            None
+        );
+    }
+
+    #[test]
+    fn crt_basis_round_trips() {
+        let basis = CrtBasis::new(&[Checked(7i64), Checked(-9), Checked(11), Checked(13)]).unwrap();
+        assert_eq!(basis.modulus().0.abs(), 7 * 9 * 11 * 13);
+        for x in (-20..9009).step_by(37).map(Checked) {
+            let residues = basis.residues(&x);
+            let y = basis.reconstruct(&residues);
+            assert_eq!(basis.residues(&y), residues);
+            assert!(Euclid::rem_euclid(&(x - y), basis.modulus()).is_zero());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "expected one residue per modulus")]
+    fn crt_basis_panics_on_too_few_residues() {
+        let basis = CrtBasis::new(&[Checked(3i64), Checked(5), Checked(7)]).unwrap();
+        basis.reconstruct(&[Checked(1), Checked(2)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected one residue per modulus")]
+    fn crt_panics_on_too_many_residues() {
+        crt(
+            &[Checked(1i64), Checked(2), Checked(3)],
+            &[Checked(3), Checked(5)],
+        );
+    }
+}
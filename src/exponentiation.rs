@@ -0,0 +1,271 @@
+//! Repeated application of an associative operation, shared by [`Exponentiation`] and
+//! [`ScalarMultiplication`].
+//!
+//! Raising to a power in a multiplicative monoid and multiplying by an integer in an additive
+//! monoid are the same algorithms over a different operation. The functions here take the
+//! operation `op` and its identity, and an exponent of little-endian 64-bit limbs.
+//!
+//! [`Exponentiation`]: crate::Exponentiation
+//! [`ScalarMultiplication`]: crate::ScalarMultiplication
+
+use crate::ConditionallySelectable;
+
+/// Returns the number of significant bits of the limbs, zero for zero.
+fn bit_length(limbs: &[u64]) -> usize {
+    match limbs.iter().rposition(|&limb| limb != 0) {
+        Some(i) => i * 64 + 64 - limbs[i].leading_zeros() as usize,
+        None => 0,
+    }
+}
+
+/// Returns bit `i` of the limbs.
+fn bit(limbs: &[u64], i: usize) -> bool {
+    limbs[i / 64] >> (i % 64) & 1 == 1
+}
+
+/// Scans the exponent from the most significant bit, applying `op` to the result and itself at
+/// every bit, and to the result and `x` at every set bit.
+pub(crate) fn binary<T: Clone>(
+    x: &T,
+    exponent: &[u64],
+    identity: T,
+    op: impl Fn(&T, &T) -> T,
+) -> T {
+    let bits = bit_length(exponent);
+    if bits == 0 {
+        return identity;
+    }
+    let mut result = x.clone();
+    for i in (0..bits - 1).rev() {
+        result = op(&result, &result);
+        if bit(exponent, i) {
+            result = op(&result, x);
+        }
+    }
+    result
+}
+
+/// Scans the exponent from the most significant bit in windows of up to `width` bits which
+/// start and end with a set bit, applying `op` once per window with a precomputed odd power of
+/// `x`.
+pub(crate) fn sliding_window<T: Clone>(
+    x: &T,
+    exponent: &[u64],
+    identity: T,
+    op: impl Fn(&T, &T) -> T,
+) -> T {
+    let bits = bit_length(exponent);
+    if bits == 0 {
+        return identity;
+    }
+    let width = match bits {
+        0..=64 => 3,
+        65..=256 => 4,
+        257..=1024 => 5,
+        _ => 6,
+    }
+    .min(bits);
+
+    // odd[j] = x^(2j + 1)
+    let square = op(x, x);
+    let mut odd = vec![x.clone()];
+    for j in 1..1 << (width - 1) {
+        odd.push(op(&odd[j - 1], &square));
+    }
+
+    // The most significant bit is set, so the first step opens a window and sets the result.
+    let mut result: Option<T> = None;
+    let mut i = bits;
+    while i > 0 {
+        if !bit(exponent, i - 1) {
+            result = result.map(|r| op(&r, &r));
+            i -= 1;
+            continue;
+        }
+        let mut low = i.saturating_sub(width);
+        while !bit(exponent, low) {
+            low += 1;
+        }
+        let window = (low..i)
+            .rev()
+            .fold(0, |w, j| w << 1 | bit(exponent, j) as usize);
+        result = Some(match result {
+            Some(mut r) => {
+                for _ in low..i {
+                    r = op(&r, &r);
+                }
+                op(&r, &odd[window >> 1])
+            }
+            None => odd[window >> 1].clone(),
+        });
+        i = low;
+    }
+    result.unwrap()
+}
+
+/// The Montgomery ladder: two applications of `op` at every bit of every limb, leading zeros
+/// included.
+///
+/// Each bit is read arithmetically and only selects, through a conditional swap, which of the
+/// two values `op` is applied to, so the sequence of operations does not depend on the bits.
+pub(crate) fn montgomery_ladder<T: ConditionallySelectable>(
+    x: &T,
+    exponent: &[u64],
+    identity: T,
+    op: impl Fn(&T, &T) -> T,
+) -> T {
+    // Invariant: r1 = op(r0, x). A set bit swaps the roles of r0 and r1 for one step.
+    let mut r0 = identity;
+    let mut r1 = x.clone();
+    for i in (0..exponent.len() * 64).rev() {
+        let bit = exponent[i / 64] >> (i % 64) & 1;
+        T::conditional_swap(&mut r0, &mut r1, bit);
+        r1 = op(&r0, &r1);
+        r0 = op(&r0, &r0);
+        T::conditional_swap(&mut r0, &mut r1, bit);
+    }
+    r0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Checked, ConditionallySelectable, DynPrimeField, Exponentiation, Gf256, PrimeFieldContext,
+        ScalarMultiplication, Zn,
+    };
+    use std::num::Wrapping;
+
+    /// Exponents of one and two limbs, with leading zero limbs and bits set at the ends.
+    const EXPONENTS: [&[u64]; 6] = [
+        &[0],
+        &[1],
+        &[1000],
+        &[u64::MAX],
+        &[0x8000000000000001, 0],
+        &[0x0123456789ABCDEF, 0xFEDCBA9876543210],
+    ];
+
+    #[test]
+    fn selection_follows_the_choice() {
+        let (a, b) = (Zn::<101>::new(3), Zn::new(7));
+        assert_eq!(Zn::conditional_select(&a, &b, 0), a);
+        assert_eq!(Zn::conditional_select(&a, &b, 1), b);
+
+        let (mut x, mut y) = (Checked(-5i64), Checked(9));
+        Checked::conditional_swap(&mut x, &mut y, 0);
+        assert_eq!((x, y), (Checked(-5), Checked(9)));
+        Checked::conditional_swap(&mut x, &mut y, 1);
+        assert_eq!((x, y), (Checked(9), Checked(-5)));
+
+        // An integer without a context is bound when selected against a residue.
+        let context = PrimeFieldContext::new([101]).unwrap();
+        let residue = DynPrimeField::from_u64(&context, 5);
+        let one = DynPrimeField::from(1);
+        let chosen = DynPrimeField::conditional_select(&residue, &one, 1);
+        assert_eq!(chosen.to_limbs(), Some([1]));
+    }
+
+    /// Returns `count` exponents of each of the given limb counts, with the top bit of the top
+    /// limb set so that they reach every window width of [`sliding_window`](super::sliding_window).
+    fn random_exponents(limb_counts: &[usize], count: usize) -> Vec<Vec<u64>> {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let mut exponents = Vec::new();
+        for &limbs in limb_counts {
+            for _ in 0..count {
+                let mut n: Vec<u64> = (0..limbs).map(|_| next()).collect();
+                n[limbs - 1] |= 1 << 63;
+                exponents.push(n);
+            }
+        }
+        exponents
+    }
+
+    /// The exponents 0 to 16, which are shorter than the default window, the fixed exponents, and
+    /// random exponents of 1, 2, 4, 5, 16 and 17 limbs, which are on either side of every change
+    /// of window width.
+    fn all_exponents() -> Vec<Vec<u64>> {
+        let mut exponents: Vec<Vec<u64>> = (0..=16).map(|n| vec![n]).collect();
+        exponents.extend(EXPONENTS.iter().map(|n| n.to_vec()));
+        exponents.extend(random_exponents(&[1, 2, 4, 5, 16, 17], 3));
+        exponents
+    }
+
+    #[test]
+    fn exponents_zero_and_one() {
+        let a = Zn::<65537>::new(3);
+        let w = Wrapping(0x9E3779B97F4A7C15u64);
+        for zero in [&[][..], &[0], &[0, 0, 0]] {
+            assert_eq!(a.square_and_multiply(zero), Zn::new(1));
+            assert_eq!(a.sliding_window_pow(zero), Zn::new(1));
+            assert_eq!(a.montgomery_ladder_pow(zero), Zn::new(1));
+            assert_eq!(w.double_and_add(zero), Wrapping(0));
+            assert_eq!(w.sliding_window_mul(zero), Wrapping(0));
+            assert_eq!(w.montgomery_ladder_mul(zero), Wrapping(0));
+        }
+        for one in [&[1][..], &[1, 0], &[1, 0, 0, 0, 0]] {
+            assert_eq!(a.square_and_multiply(one), a);
+            assert_eq!(a.sliding_window_pow(one), a);
+            assert_eq!(a.montgomery_ladder_pow(one), a);
+            assert_eq!(w.double_and_add(one), w);
+            assert_eq!(w.sliding_window_mul(one), w);
+            assert_eq!(w.montgomery_ladder_mul(one), w);
+        }
+    }
+
+    #[test]
+    fn methods_agree_with_reductions_of_the_exponent() {
+        // 3 generates the multiplicative group of order 2¹⁶ modulo 65537, and 2¹⁶ divides 2⁶⁴, so
+        // 3ⁿ only depends on the low 16 bits of n. Likewise n · w modulo 2⁶⁴ only depends on the
+        // low limb of n.
+        let a = Zn::<65537>::new(3);
+        let w = Wrapping(0x9E3779B97F4A7C15u64);
+        for n in all_exponents() {
+            let mut expected = 1u64;
+            for _ in 0..n[0] & 0xFFFF {
+                expected = expected * 3 % 65537;
+            }
+            let expected = Zn::new(expected);
+            assert_eq!(a.square_and_multiply(&n), expected, "n = {n:x?}");
+            assert_eq!(a.sliding_window_pow(&n), expected, "n = {n:x?}");
+            assert_eq!(a.montgomery_ladder_pow(&n), expected, "n = {n:x?}");
+
+            let expected = w * Wrapping(n[0]);
+            assert_eq!(w.double_and_add(&n), expected, "n = {n:x?}");
+            assert_eq!(w.sliding_window_mul(&n), expected, "n = {n:x?}");
+            assert_eq!(w.montgomery_ladder_mul(&n), expected, "n = {n:x?}");
+        }
+    }
+
+    #[test]
+    fn all_methods_agree() {
+        let context = PrimeFieldContext::new([1_000_000_007]).unwrap();
+        let x = DynPrimeField::from_u64(&context, 123_456_789);
+        let a = Zn::<65537>::new(3);
+        let b = Gf256::new(0x53);
+        let w = Wrapping(0x9E3779B97F4A7C15u64);
+        for n in all_exponents() {
+            let n = &n[..];
+            let expected = x.square_and_multiply(n);
+            assert_eq!(x.sliding_window_pow(n), expected, "n = {n:x?}");
+            assert_eq!(x.montgomery_ladder_pow(n), expected, "n = {n:x?}");
+
+            let expected = a.double_and_add(n);
+            assert_eq!(a.sliding_window_mul(n), expected, "n = {n:x?}");
+            assert_eq!(a.montgomery_ladder_mul(n), expected, "n = {n:x?}");
+
+            let expected = b.square_and_multiply(n);
+            assert_eq!(b.sliding_window_pow(n), expected, "n = {n:x?}");
+            assert_eq!(b.montgomery_ladder_pow(n), expected, "n = {n:x?}");
+
+            let expected = w.square_and_multiply(n);
+            assert_eq!(w.sliding_window_pow(n), expected, "n = {n:x?}");
+            assert_eq!(w.montgomery_ladder_pow(n), expected, "n = {n:x?}");
+        }
+    }
+}
@@ -0,0 +1,967 @@
+//! Quadratic and cubic extensions of fields.
+//!
+//! [`QuadraticExtension`] and [`CubicExtension`] adjoin a square or cube root of a non-residue to
+//! any [`Field`]. Since the extensions are fields themselves they can be stacked, which is how
+//! the towers 𝔽ₚ ⊂ 𝔽ₚ² ⊂ 𝔽ₚ⁶ ⊂ 𝔽ₚ¹² of pairing-friendly curves are built.
+
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, BigUInt, CommutativeAddition,
+    CommutativeMultiplication, ConditionallySelectable, Distributive, EuclideanValuation,
+    ExactDivision, Exponentiation, Field, FieldExtension, FiniteField, FiniteSet, NoZeroDivisors,
+    VectorSpace,
+};
+use num_traits::{Euclid, Inv, One, Zero};
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// The defining constant of a [`QuadraticExtension`] of `F`.
+///
+/// The implementor promises that β = [`non_residue`](Self::non_residue) is not a square in `F`,
+/// so that x² - β is irreducible and F\[u\]/(u² - β) is a field.
+pub trait QuadraticNonResidue<F> {
+    /// Returns the non-residue β, so that u² = β.
+    fn non_residue() -> F;
+
+    /// Returns γₖ = β^((pᵏ - 1) / 2) for k = `power`, where p is the odd characteristic of `F`,
+    /// so that u^(pᵏ) = γₖu.
+    ///
+    /// It is used by [`FiniteField::absolute_frobenius_map`] for every power k below the degree of
+    /// F(u) over 𝔽ₚ. The default computes the power once per thread, `F`, `Self` and k, and
+    /// caches it.
+    fn frobenius_coefficient(power: usize) -> F
+    where
+        F: FiniteField + 'static,
+        Self: Sized + 'static,
+    {
+        cached::<(Self, F), _>(power, || {
+            let (exponent, _) = frobenius_exponent::<F>(1, power, 2);
+            Self::non_residue().square_and_multiply(exponent.as_limbs())
+        })
+    }
+}
+
+/// The defining constant of a [`CubicExtension`] of `F`.
+///
+/// The implementor promises that β = [`non_residue`](Self::non_residue) is not a cube in `F`, so
+/// that x³ - β is irreducible and F\[v\]/(v³ - β) is a field.
+pub trait CubicNonResidue<F> {
+    /// Returns the non-residue β, so that v³ = β.
+    fn non_residue() -> F;
+
+    /// Returns ω = σ(v) / v, where σ is the Frobenius automorphism of F(v) over F.
+    ///
+    /// For a finite field F of order q this is β^((q - 1) / 3), a primitive cube root of unity.
+    /// It is used by [`FieldExtension::frobenius_map`], and is usually precomputed.
+    fn relative_frobenius_coefficient() -> F;
+
+    /// Returns (β^⌊pᵏ/3⌋, β^⌊2pᵏ/3⌋) for k = `power`, where p is the characteristic of `F`.
+    ///
+    /// These are the coefficients of v^(pᵏ) and v^(2pᵏ), which are multiples of v and v² when
+    /// pᵏ ≡ 1 (mod 3), and of v² and v when pᵏ ≡ 2 (mod 3). They are used by
+    /// [`FiniteField::absolute_frobenius_map`] for every power k below the degree of F(v) over
+    /// 𝔽ₚ. The default computes the powers once per thread, `F`, `Self` and k, and caches them.
+    fn frobenius_coefficients(power: usize) -> (F, F)
+    where
+        F: FiniteField + 'static,
+        Self: Sized + 'static,
+    {
+        cached::<(Self, F), _>(power, || {
+            let beta = Self::non_residue();
+            let (first, _) = frobenius_exponent::<F>(1, power, 3);
+            let (second, _) = frobenius_exponent::<F>(2, power, 3);
+            (
+                beta.square_and_multiply(first.as_limbs()),
+                beta.square_and_multiply(second.as_limbs()),
+            )
+        })
+    }
+}
+
+/// Returns the value of type `V` stored for the type `K` and `index`, computing it with `compute`
+/// on first use.
+///
+/// A static in a generic function is shared by all of its instantiations, so the values of every
+/// type live in one map, keyed by [`TypeId`]. Each thread keeps its own map, which spares the
+/// values a `Send + Sync` bound.
+fn cached<K: 'static, V: Clone + 'static>(index: usize, compute: impl FnOnce() -> V) -> V {
+    type Cache = HashMap<(TypeId, TypeId, usize), Box<dyn Any>>;
+    thread_local! {
+        static CACHE: RefCell<Cache> = RefCell::new(HashMap::new());
+    }
+    let key = (TypeId::of::<K>(), TypeId::of::<V>(), index);
+    let hit = CACHE.with(|cache| {
+        let cache = cache.borrow();
+        cache
+            .get(&key)
+            .and_then(|value| value.downcast_ref::<V>())
+            .cloned()
+    });
+    hit.unwrap_or_else(|| {
+        // Computed without holding the borrow, since `compute` may fill the cache of a subfield.
+        let value = compute();
+        CACHE.with(|cache| cache.borrow_mut().insert(key, Box::new(value.clone())));
+        value
+    })
+}
+
+/// Returns ⌊m·pᵏ / d⌋ and m·pᵏ mod d for m = `multiple`, k = `power` and d = `divisor`, where p is
+/// the characteristic of `F`.
+fn frobenius_exponent<F: FiniteField>(multiple: u64, power: usize, divisor: u64) -> (BigUInt, u64) {
+    let p: BigUInt = F::characteristic().into();
+    let power = u32::try_from(power).expect("Frobenius power too large");
+    (BigUInt::from(multiple) * p.pow(power)).div_rem_u64(divisor)
+}
+
+/// Represents the quadratic extension F(u) = F\[u\]/(u² - β) of a field F.
+///
+/// # Mathematical Definition
+/// Elements are c₀ + c₁u with c₀, c₁ ∈ F, multiplied using u² = β for the non-residue β given by
+/// `C`:
+/// (a₀ + a₁u)(b₀ + b₁u) = (a₀b₀ + βa₁b₁) + (a₀b₁ + a₁b₀)u
+///
+/// # Properties
+/// - F(u) is a field and a vector space of dimension 2 over F
+/// - The conjugate of a₀ + a₁u is a₀ - a₁u
+/// - Tr(a₀ + a₁u) = 2a₀ and N(a₀ + a₁u) = a₀² - βa₁²
+///
+/// # Example
+/// ```
+/// use noether::{
+///     BigUInt, FieldExtension, FiniteField, Fp, QuadraticExtension, QuadraticNonResidue,
+/// };
+///
+/// /// 3 is not a square modulo 7.
+/// struct Three;
+///
+/// impl QuadraticNonResidue<Fp<7>> for Three {
+///     fn non_residue() -> Fp<7> {
+///         Fp::new(3)
+///     }
+/// }
+///
+/// type F49 = QuadraticExtension<Fp<7>, Three>;
+///
+/// let u = F49::new(Fp::new(0), Fp::new(1));
+/// assert_eq!(u * u, F49::from(Fp::new(3)));
+/// assert_eq!(u.norm(), Fp::new(4));
+/// assert_eq!(F49::order(), BigUInt::from(49u64));
+/// ```
+pub struct QuadraticExtension<F, C> {
+    /// The coefficient of 1.
+    pub c0: F,
+    /// The coefficient of u.
+    pub c1: F,
+    config: PhantomData<fn() -> C>,
+}
+
+impl<F, C> QuadraticExtension<F, C> {
+    /// Creates the element c₀ + c₁u.
+    pub const fn new(c0: F, c1: F) -> Self {
+        QuadraticExtension {
+            c0,
+            c1,
+            config: PhantomData,
+        }
+    }
+}
+
+/// Represents the cubic extension F(v) = F\[v\]/(v³ - β) of a field F.
+///
+/// # Mathematical Definition
+/// Elements are c₀ + c₁v + c₂v² with c₀, c₁, c₂ ∈ F, multiplied using v³ = β for the non-residue
+/// β given by `C`.
+///
+/// # Properties
+/// - F(v) is a field and a vector space of dimension 3 over F
+/// - Tr(a₀ + a₁v + a₂v²) = 3a₀
+/// - N(a₀ + a₁v + a₂v²) = a₀³ + βa₁³ + β²a₂³ - 3βa₀a₁a₂
+pub struct CubicExtension<F, C> {
+    /// The coefficient of 1.
+    pub c0: F,
+    /// The coefficient of v.
+    pub c1: F,
+    /// The coefficient of v².
+    pub c2: F,
+    config: PhantomData<fn() -> C>,
+}
+
+impl<F, C> CubicExtension<F, C> {
+    /// Creates the element c₀ + c₁v + c₂v².
+    pub const fn new(c0: F, c1: F, c2: F) -> Self {
+        CubicExtension {
+            c0,
+            c1,
+            c2,
+            config: PhantomData,
+        }
+    }
+}
+
+// Derived impls would require the configuration type to implement the traits as well.
+
+impl<F: Clone, C> Clone for QuadraticExtension<F, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.c0.clone(), self.c1.clone())
+    }
+}
+
+impl<F: Copy, C> Copy for QuadraticExtension<F, C> {}
+
+impl<F: PartialEq, C> PartialEq for QuadraticExtension<F, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1
+    }
+}
+
+impl<F: Eq, C> Eq for QuadraticExtension<F, C> {}
+
+impl<F: Hash, C> Hash for QuadraticExtension<F, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.c0.hash(state);
+        self.c1.hash(state);
+    }
+}
+
+impl<F: fmt::Debug, C> fmt::Debug for QuadraticExtension<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QuadraticExtension")
+            .field("c0", &self.c0)
+            .field("c1", &self.c1)
+            .finish()
+    }
+}
+
+impl<F: fmt::Display, C> fmt::Display for QuadraticExtension<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.c0, self.c1)
+    }
+}
+
+impl<F: Clone, C> Clone for CubicExtension<F, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.c0.clone(), self.c1.clone(), self.c2.clone())
+    }
+}
+
+impl<F: Copy, C> Copy for CubicExtension<F, C> {}
+
+impl<F: PartialEq, C> PartialEq for CubicExtension<F, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1 && self.c2 == other.c2
+    }
+}
+
+impl<F: Eq, C> Eq for CubicExtension<F, C> {}
+
+impl<F: Hash, C> Hash for CubicExtension<F, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.c0.hash(state);
+        self.c1.hash(state);
+        self.c2.hash(state);
+    }
+}
+
+impl<F: fmt::Debug, C> fmt::Debug for CubicExtension<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CubicExtension")
+            .field("c0", &self.c0)
+            .field("c1", &self.c1)
+            .field("c2", &self.c2)
+            .finish()
+    }
+}
+
+impl<F: fmt::Display, C> fmt::Display for CubicExtension<F, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.c0, self.c1, self.c2)
+    }
+}
+
+impl<F: Zero, C> From<F> for QuadraticExtension<F, C> {
+    /// Embeds an element of the base field.
+    fn from(c0: F) -> Self {
+        Self::new(c0, F::zero())
+    }
+}
+
+impl<F: Zero, C> From<F> for CubicExtension<F, C> {
+    /// Embeds an element of the base field.
+    fn from(c0: F) -> Self {
+        Self::new(c0, F::zero(), F::zero())
+    }
+}
+
+// Quadratic extension arithmetic
+
+impl<F: Field, C> Add for QuadraticExtension<F, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.c0 + rhs.c0, self.c1 + rhs.c1)
+    }
+}
+
+impl<F: Field, C> Sub for QuadraticExtension<F, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.c0 - rhs.c0, self.c1 - rhs.c1)
+    }
+}
+
+impl<F: Field, C> Neg for QuadraticExtension<F, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1)
+    }
+}
+
+impl<F: Field, C: QuadraticNonResidue<F>> Mul for QuadraticExtension<F, C> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Karatsuba: a₀b₁ + a₁b₀ = (a₀ + a₁)(b₀ + b₁) - a₀b₀ - a₁b₁
+        let v0 = self.c0.clone() * rhs.c0.clone();
+        let v1 = self.c1.clone() * rhs.c1.clone();
+        let c1 = (self.c0 + self.c1) * (rhs.c0 + rhs.c1) - v0.clone() - v1.clone();
+        Self::new(v0 + C::non_residue() * v1, c1)
+    }
+}
+
+impl<F: Field, C: QuadraticNonResidue<F>> Inv for QuadraticExtension<F, C> {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse, the conjugate divided by the norm.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    fn inv(self) -> Self {
+        let norm = self.norm();
+        assert!(
+            !norm.is_zero(),
+            "attempt to invert zero in QuadraticExtension"
+        );
+        let norm_inv = norm.inv();
+        Self::new(self.c0 * norm_inv.clone(), -(self.c1 * norm_inv))
+    }
+}
+
+impl<F: Field, C: QuadraticNonResidue<F>> Zero for QuadraticExtension<F, C> {
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+}
+
+impl<F: Field, C: QuadraticNonResidue<F>> One for QuadraticExtension<F, C> {
+    fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+}
+
+impl<F: Field, C: QuadraticNonResidue<F>> VectorSpace for QuadraticExtension<F, C> {
+    type Scalar = F;
+
+    fn scale(&self, scalar: &F) -> Self {
+        Self::new(
+            self.c0.clone() * scalar.clone(),
+            self.c1.clone() * scalar.clone(),
+        )
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(2)
+    }
+}
+
+impl<F: Field, C: QuadraticNonResidue<F>> FieldExtension for QuadraticExtension<F, C> {
+    type BaseField = F;
+
+    fn degree() -> usize {
+        2
+    }
+
+    fn to_base_field(&self) -> Option<F> {
+        self.c1.is_zero().then(|| self.c0.clone())
+    }
+
+    /// Applies σᵖᵒʷᵉʳ, where σ(c₀ + c₁u) = c₀ - c₁u is conjugation.
+    ///
+    /// This assumes that F has odd characteristic, which is the case for every finite field
+    /// with a quadratic extension of this form: in characteristic 2 every element of a finite
+    /// field is a square, so u² - β is never irreducible.
+    fn frobenius_map(&self, power: usize) -> Self {
+        // σ(u) = u^q = u · β^((q - 1) / 2) = -u, since β is not a square.
+        if power % 2 == 0 {
+            self.clone()
+        } else {
+            Self::new(self.c0.clone(), -self.c1.clone())
+        }
+    }
+
+    fn trace(&self) -> F {
+        self.c0.clone() + self.c0.clone()
+    }
+
+    fn norm(&self) -> F {
+        self.c0.clone() * self.c0.clone() - C::non_residue() * self.c1.clone() * self.c1.clone()
+    }
+}
+
+impl<F: FiniteSet, C> FiniteSet for QuadraticExtension<F, C> {
+    fn elements() -> impl Iterator<Item = Self> {
+        F::elements().flat_map(|c1| F::elements().map(move |c0| Self::new(c0, c1.clone())))
+    }
+}
+
+/// Divides both coefficients, since n(c₀ + c₁u) = nc₀ + nc₁u.
+impl<F: Field + ExactDivision, C: QuadraticNonResidue<F>> ExactDivision
+    for QuadraticExtension<F, C>
+{
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        Some(Self::new(self.c0.div_exact(n)?, self.c1.div_exact(n)?))
+    }
+}
+
+impl<F: ConditionallySelectable, C> ConditionallySelectable for QuadraticExtension<F, C> {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self::new(
+            F::conditional_select(&a.c0, &b.c0, choice),
+            F::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+impl<F: FiniteField + 'static, C: QuadraticNonResidue<F> + 'static> FiniteField
+    for QuadraticExtension<F, C>
+{
+    type ScalarType = BigUInt;
+
+    fn characteristic() -> BigUInt {
+        F::characteristic().into()
+    }
+
+    /// Returns q², where q is the order of `F`.
+    fn order() -> BigUInt {
+        let q: BigUInt = F::order().into();
+        q.pow(2)
+    }
+
+    fn extension_degree() -> u32 {
+        2 * F::extension_degree()
+    }
+
+    /// Applies φᵏ(c₀ + c₁u) = φᵏ(c₀) + φᵏ(c₁)γₖu, with γₖ from
+    /// [`QuadraticNonResidue::frobenius_coefficient`].
+    fn absolute_frobenius_map(&self, power: usize) -> Self {
+        let power = power % Self::extension_degree() as usize;
+        if power == 0 {
+            return self.clone();
+        }
+        Self::new(
+            self.c0.absolute_frobenius_map(power),
+            self.c1.absolute_frobenius_map(power) * C::frobenius_coefficient(power),
+        )
+    }
+}
+
+// Cubic extension arithmetic
+
+impl<F: Field, C> Add for CubicExtension<F, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.c0 + rhs.c0, self.c1 + rhs.c1, self.c2 + rhs.c2)
+    }
+}
+
+impl<F: Field, C> Sub for CubicExtension<F, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.c0 - rhs.c0, self.c1 - rhs.c1, self.c2 - rhs.c2)
+    }
+}
+
+impl<F: Field, C> Neg for CubicExtension<F, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+impl<F: Field, C: CubicNonResidue<F>> Mul for CubicExtension<F, C> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let CubicExtension {
+            c0: a0,
+            c1: a1,
+            c2: a2,
+            ..
+        } = self;
+        let CubicExtension {
+            c0: b0,
+            c1: b1,
+            c2: b2,
+            ..
+        } = rhs;
+        let beta = C::non_residue();
+        let c0 = a0.clone() * b0.clone()
+            + beta.clone() * (a1.clone() * b2.clone() + a2.clone() * b1.clone());
+        let c1 = a0.clone() * b1.clone() + a1.clone() * b0.clone() + beta * a2.clone() * b2.clone();
+        let c2 = a0 * b2 + a1 * b1 + a2 * b0;
+        Self::new(c0, c1, c2)
+    }
+}
+
+impl<F: Field, C: CubicNonResidue<F>> CubicExtension<F, C> {
+    /// Returns the cofactors t with self · (t₀ + t₁v + t₂v²) = N(self).
+    fn adjugate(&self) -> (F, F, F) {
+        let beta = C::non_residue();
+        let (a0, a1, a2) = (&self.c0, &self.c1, &self.c2);
+        let t0 = a0.clone() * a0.clone() - beta.clone() * a1.clone() * a2.clone();
+        let t1 = beta * a2.clone() * a2.clone() - a0.clone() * a1.clone();
+        let t2 = a1.clone() * a1.clone() - a0.clone() * a2.clone();
+        (t0, t1, t2)
+    }
+}
+
+impl<F: Field, C: CubicNonResidue<F>> Inv for CubicExtension<F, C> {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse, the adjugate divided by the norm.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    fn inv(self) -> Self {
+        let norm = self.norm();
+        assert!(!norm.is_zero(), "attempt to invert zero in CubicExtension");
+        let norm_inv = norm.inv();
+        let (t0, t1, t2) = self.adjugate();
+        Self::new(t0 * norm_inv.clone(), t1 * norm_inv.clone(), t2 * norm_inv)
+    }
+}
+
+impl<F: Field, C: CubicNonResidue<F>> Zero for CubicExtension<F, C> {
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<F: Field, C: CubicNonResidue<F>> One for CubicExtension<F, C> {
+    fn one() -> Self {
+        Self::new(F::one(), F::zero(), F::zero())
+    }
+}
+
+impl<F: Field, C: CubicNonResidue<F>> VectorSpace for CubicExtension<F, C> {
+    type Scalar = F;
+
+    fn scale(&self, scalar: &F) -> Self {
+        Self::new(
+            self.c0.clone() * scalar.clone(),
+            self.c1.clone() * scalar.clone(),
+            self.c2.clone() * scalar.clone(),
+        )
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(3)
+    }
+}
+
+impl<F: Field, C: CubicNonResidue<F>> FieldExtension for CubicExtension<F, C> {
+    type BaseField = F;
+
+    fn degree() -> usize {
+        3
+    }
+
+    fn to_base_field(&self) -> Option<F> {
+        (self.c1.is_zero() && self.c2.is_zero()).then(|| self.c0.clone())
+    }
+
+    /// Applies σᵖᵒʷᵉʳ, where σ(c₀ + c₁v + c₂v²) = c₀ + c₁ωv + c₂ω²v².
+    fn frobenius_map(&self, power: usize) -> Self {
+        let omega = C::relative_frobenius_coefficient();
+        match power % 3 {
+            0 => self.clone(),
+            1 => Self::new(
+                self.c0.clone(),
+                self.c1.clone() * omega.clone(),
+                self.c2.clone() * omega.clone() * omega,
+            ),
+            _ => Self::new(
+                self.c0.clone(),
+                self.c1.clone() * omega.clone() * omega.clone(),
+                self.c2.clone() * omega,
+            ),
+        }
+    }
+
+    fn trace(&self) -> F {
+        self.c0.clone() + self.c0.clone() + self.c0.clone()
+    }
+
+    fn norm(&self) -> F {
+        // a₀t₀ + β(a₂t₁ + a₁t₂) = a₀³ + βa₁³ + β²a₂³ - 3βa₀a₁a₂
+        let (t0, t1, t2) = self.adjugate();
+        self.c0.clone() * t0 + C::non_residue() * (self.c2.clone() * t1 + self.c1.clone() * t2)
+    }
+}
+
+impl<F: FiniteSet, C> FiniteSet for CubicExtension<F, C> {
+    fn elements() -> impl Iterator<Item = Self> {
+        F::elements().flat_map(|c2| {
+            F::elements().flat_map(move |c1| {
+                let c2 = c2.clone();
+                F::elements().map(move |c0| Self::new(c0, c1.clone(), c2.clone()))
+            })
+        })
+    }
+}
+
+/// Divides the three coefficients, since n(c₀ + c₁v + c₂v²) = nc₀ + nc₁v + nc₂v².
+impl<F: Field + ExactDivision, C: CubicNonResidue<F>> ExactDivision for CubicExtension<F, C> {
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        Some(Self::new(
+            self.c0.div_exact(n)?,
+            self.c1.div_exact(n)?,
+            self.c2.div_exact(n)?,
+        ))
+    }
+}
+
+impl<F: ConditionallySelectable, C> ConditionallySelectable for CubicExtension<F, C> {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self::new(
+            F::conditional_select(&a.c0, &b.c0, choice),
+            F::conditional_select(&a.c1, &b.c1, choice),
+            F::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+impl<F: FiniteField + 'static, C: CubicNonResidue<F> + 'static> FiniteField
+    for CubicExtension<F, C>
+{
+    type ScalarType = BigUInt;
+
+    fn characteristic() -> BigUInt {
+        F::characteristic().into()
+    }
+
+    /// Returns q³, where q is the order of `F`.
+    fn order() -> BigUInt {
+        let q: BigUInt = F::order().into();
+        q.pow(3)
+    }
+
+    fn extension_degree() -> u32 {
+        3 * F::extension_degree()
+    }
+
+    /// Applies φᵏ to each coefficient and multiplies by the coefficients (δ₁, δ₂) from
+    /// [`CubicNonResidue::frobenius_coefficients`]: φᵏ(c₀ + c₁v + c₂v²) is
+    /// φᵏ(c₀) + φᵏ(c₁)δ₁v + φᵏ(c₂)δ₂v² when pᵏ ≡ 1 (mod 3), and φᵏ(c₀) + φᵏ(c₂)δ₂v + φᵏ(c₁)δ₁v²
+    /// otherwise.
+    fn absolute_frobenius_map(&self, power: usize) -> Self {
+        let power = power % Self::extension_degree() as usize;
+        if power == 0 {
+            return self.clone();
+        }
+        let (d1, d2) = C::frobenius_coefficients(power);
+        let c1 = self.c1.absolute_frobenius_map(power) * d1;
+        let c2 = self.c2.absolute_frobenius_map(power) * d2;
+        match cached::<F, u64>(power, || frobenius_exponent::<F>(1, power, 3).1) {
+            1 => Self::new(self.c0.absolute_frobenius_map(power), c1, c2),
+            _ => Self::new(self.c0.absolute_frobenius_map(power), c2, c1),
+        }
+    }
+}
+
+// Operations shared by both extensions, which only rely on the ones above
+
+macro_rules! extension_field {
+    ($Extension:ident, $NonResidue:ident) => {
+        impl<F: Field, C: $NonResidue<F>> Div for $Extension<F, C> {
+            type Output = Self;
+
+            /// # Panics
+            /// Panics if `rhs` is zero.
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self {
+                self * rhs.inv()
+            }
+        }
+
+        impl<F: Field, C: $NonResidue<F>> Rem for $Extension<F, C> {
+            type Output = Self;
+
+            /// Returns the remainder of field division, which is always zero.
+            ///
+            /// # Panics
+            /// Panics if `rhs` is zero.
+            fn rem(self, rhs: Self) -> Self {
+                assert!(
+                    !rhs.is_zero(),
+                    "attempt to calculate the remainder with a divisor of zero"
+                );
+                Self::zero()
+            }
+        }
+
+        impl<F: Field, C> AddAssign for $Extension<F, C> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = self.clone() + rhs;
+            }
+        }
+
+        impl<F: Field, C> SubAssign for $Extension<F, C> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = self.clone() - rhs;
+            }
+        }
+
+        impl<F: Field, C: $NonResidue<F>> MulAssign for $Extension<F, C> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = self.clone() * rhs;
+            }
+        }
+
+        impl<F: Field, C: $NonResidue<F>> DivAssign for $Extension<F, C> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = self.clone() / rhs;
+            }
+        }
+
+        impl<F: Field, C: $NonResidue<F>> RemAssign for $Extension<F, C> {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = self.clone() % rhs;
+            }
+        }
+
+        impl<F: Field, C: $NonResidue<F>> Euclid for $Extension<F, C> {
+            fn div_euclid(&self, v: &Self) -> Self {
+                self.clone() / v.clone()
+            }
+
+            fn rem_euclid(&self, v: &Self) -> Self {
+                self.clone() % v.clone()
+            }
+        }
+
+        impl<F: Field, C: $NonResidue<F>> EuclideanValuation for $Extension<F, C> {
+            fn euclidean_size(&self) -> u128 {
+                u128::from(!self.is_zero())
+            }
+        }
+
+        impl<F: Field, C> CommutativeAddition for $Extension<F, C> {}
+        impl<F: Field, C> AssociativeAddition for $Extension<F, C> {}
+        impl<F: Field, C> CommutativeMultiplication for $Extension<F, C> {}
+        impl<F: Field, C> AssociativeMultiplication for $Extension<F, C> {}
+        impl<F: Field, C> Distributive for $Extension<F, C> {}
+        impl<F: Field, C> NoZeroDivisors for $Extension<F, C> {}
+    };
+}
+
+extension_field!(QuadraticExtension, QuadraticNonResidue);
+extension_field!(CubicExtension, CubicNonResidue);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FinitePrimeField, Fp, PrimeFieldParams, UInt};
+
+    /// The base field of the BN254 curve, of 254 bits.
+    struct Bn254;
+
+    impl PrimeFieldParams<4> for Bn254 {
+        const MODULUS: [u64; 4] = [
+            0x3C208C16D87CFD47,
+            0x97816A916871CA8D,
+            0xB85045B68181585D,
+            0x30644E72E131A029,
+        ];
+    }
+
+    type Fq = FinitePrimeField<Bn254, 4>;
+
+    /// Fq2 = Fq[u]/(u² + 1).
+    struct MinusOne;
+
+    impl QuadraticNonResidue<Fq> for MinusOne {
+        fn non_residue() -> Fq {
+            -Fq::one()
+        }
+    }
+
+    type Fq2 = QuadraticExtension<Fq, MinusOne>;
+
+    /// Fq6 = Fq2[v]/(v³ - ξ) with ξ = 9 + u.
+    struct Xi;
+
+    impl CubicNonResidue<Fq2> for Xi {
+        fn non_residue() -> Fq2 {
+            Fq2::new(Fq::from(9), Fq::one())
+        }
+
+        fn relative_frobenius_coefficient() -> Fq2 {
+            // β^((p² - 1) / 3), where p² ≡ 1 (mod 3).
+            let (exponent, _) = frobenius_exponent::<Fq>(1, 2, 3);
+            Self::non_residue().square_and_multiply(exponent.as_limbs())
+        }
+    }
+
+    type Fq6 = CubicExtension<Fq2, Xi>;
+
+    /// Fq12 = Fq6[w]/(w² - v).
+    struct V;
+
+    impl QuadraticNonResidue<Fq6> for V {
+        fn non_residue() -> Fq6 {
+            Fq6::new(Fq2::zero(), Fq2::one(), Fq2::zero())
+        }
+    }
+
+    type Fq12 = QuadraticExtension<Fq6, V>;
+
+    /// F25 = 𝔽₅[u]/(u² - 2), whose characteristic 5 ≡ 2 (mod 3) makes the absolute Frobenius of
+    /// a cubic extension swap the coefficients of v and v².
+    struct Two;
+
+    impl QuadraticNonResidue<Fp<5>> for Two {
+        fn non_residue() -> Fp<5> {
+            Fp::new(2)
+        }
+    }
+
+    type F25 = QuadraticExtension<Fp<5>, Two>;
+
+    /// F15625 = F25[v]/(v³ - (1 + u)).
+    struct OnePlusU;
+
+    impl CubicNonResidue<F25> for OnePlusU {
+        fn non_residue() -> F25 {
+            F25::new(Fp::new(1), Fp::new(1))
+        }
+
+        fn relative_frobenius_coefficient() -> F25 {
+            Self::non_residue().square_and_multiply(&[8])
+        }
+    }
+
+    type F15625 = CubicExtension<F25, OnePlusU>;
+
+    /// Checks that φᵏ(a) = a^(pᵏ) for every power k up to the degree over the prime field.
+    fn check_frobenius<F: FiniteField + fmt::Debug>(a: &F) {
+        let p: BigUInt = F::characteristic().into();
+        let mut power = a.clone();
+        for k in 0..=F::extension_degree() as usize {
+            assert_eq!(a.absolute_frobenius_map(k), power, "power {k}");
+            power = power.square_and_multiply(p.as_limbs());
+        }
+    }
+
+    fn sample() -> Fq12 {
+        let fq2 = |a: u64, b: u64| Fq2::new(Fq::from(a), Fq::from(b));
+        Fq12::new(
+            Fq6::new(fq2(1, 2), fq2(3, 4), fq2(5, 6)),
+            Fq6::new(fq2(7, 8), fq2(9, 10), fq2(11, 12)),
+        )
+    }
+
+    #[test]
+    fn tower_orders_are_exact() {
+        let p = BigUInt::from(UInt::from_limbs(Bn254::MODULUS));
+        assert_eq!(Fq12::characteristic(), p);
+        assert_eq!(Fq2::order(), p.pow(2));
+        assert_eq!(Fq6::order(), p.pow(6));
+        assert_eq!(
+            Fq12::order(),
+            (0..12).fold(BigUInt::one(), |q, _| q * p.clone())
+        );
+        // 254 bits per factor of p would overflow any fixed width chosen for the base field.
+        assert_eq!(p.bits(), 254);
+        assert_eq!(Fq12::order().bits(), 3044);
+        assert_eq!(Fq12::extension_degree(), 12);
+    }
+
+    #[test]
+    fn elements_raised_to_the_order_are_fixed() {
+        // The multiplicative group of Fq12 has order q - 1, so aᵍ = a for every a.
+        let a = sample();
+        assert_eq!(a.square_and_multiply(Fq12::order().as_limbs()), a);
+        let omega = Xi::relative_frobenius_coefficient();
+        assert_eq!(omega * omega * omega, Fq2::one());
+        assert_ne!(omega, Fq2::one());
+    }
+
+    #[test]
+    fn frobenius_raises_to_powers_of_the_characteristic() {
+        let a = sample();
+        check_frobenius(&a.c0.c1);
+        check_frobenius(&a.c1);
+        check_frobenius(&a);
+        check_frobenius(&Fq::from(5));
+    }
+
+    #[test]
+    fn relative_frobenius_is_a_power_of_the_absolute_one() {
+        let a = sample();
+        assert_eq!(a.frobenius_map(1), a.absolute_frobenius_map(6));
+        assert_eq!(a.c1.frobenius_map(1), a.c1.absolute_frobenius_map(2));
+        assert_eq!(a.c1.frobenius_map(2), a.c1.absolute_frobenius_map(4));
+        assert_eq!(a.c1.c2.frobenius_map(1), a.c1.c2.absolute_frobenius_map(1));
+        assert_eq!(Fq12::from(a.norm()), a * a.absolute_frobenius_map(6));
+    }
+
+    #[test]
+    fn frobenius_swaps_cubic_coefficients_when_p_is_2_mod_3() {
+        assert_eq!(F15625::order(), BigUInt::from(15625u64));
+        for a in F15625::elements().step_by(7) {
+            check_frobenius(&a);
+        }
+    }
+
+    #[test]
+    fn frobenius_coefficients_are_computed_once_per_type() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let compute = || {
+            calls.set(calls.get() + 1);
+            Fq2::new(Fq::from(3), Fq::one())
+        };
+        let first = cached::<(MinusOne, Fq), _>(7, compute);
+        assert_eq!(cached::<(MinusOne, Fq), _>(7, compute), first);
+        assert_eq!(calls.get(), 1);
+        // Other types, indices and value types have their own entries.
+        cached::<(Two, Fq), _>(7, compute);
+        cached::<(MinusOne, Fq), _>(8, compute);
+        cached::<(MinusOne, Fq), _>(7, || (compute(), compute()));
+        assert_eq!(calls.get(), 5);
+
+        let (d1, d2) = Xi::frobenius_coefficients(1);
+        assert_eq!(Xi::frobenius_coefficients(1), (d1, d2));
+        assert_eq!(MinusOne::frobenius_coefficient(1), -Fq::one());
+    }
+}
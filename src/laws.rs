@@ -0,0 +1,1127 @@
+//! Property-based checkers for the algebraic marker traits and the structure hierarchy.
+//!
+//! The marker traits (`CommutativeAddition`, `AssociativeMultiplication`, ...) are promises made
+//! by the implementor which the compiler cannot verify. The functions in this module draw values
+//! from a caller supplied generator and test the promised law on every sample, returning the
+//! first counterexample found.
+//!
+//! The `check_*` functions named after a structure (`check_ring`, `check_field`, ...) run every
+//! axiom implied by the trait chain leading up to that structure, and report the level of the
+//! hierarchy which broke. They are intended to be called from `#[test]` functions:
+//!
+//! ```ignore
+//! #[test]
+//! fn my_field_is_a_field() {
+//!     noether::laws::check_field(random_element, 1000).unwrap();
+//! }
+//! ```
+//!
+//! A passing check is evidence, not proof: only the sampled values are examined.
+
+use crate::{
+    AdditiveAbelianGroup, AdditiveGroup, AdditiveMagma, AdditiveMonoid, AdditiveSemigroup,
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    CommutativeRing, Distributive, EuclideanDomain, Field, FiniteField, FiniteSet, IntegralDomain,
+    MultiplicativeAbelianGroup, MultiplicativeGroup, MultiplicativeMagma, MultiplicativeMonoid,
+    MultiplicativeSemigroup, NoZeroDivisors, Ring,
+};
+use num_traits::{Euclid, One, Zero};
+use std::any::type_name;
+use std::fmt;
+
+/// An algebraic law which can be checked on concrete values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Law {
+    /// a += b leaves a + b in a
+    AddAssign,
+    /// a *= b leaves a * b in a
+    MulAssign,
+    /// a + b = b + a
+    CommutativeAddition,
+    /// (a + b) + c = a + (b + c)
+    AssociativeAddition,
+    /// a * b = b * a
+    CommutativeMultiplication,
+    /// (a * b) * c = a * (b * c)
+    AssociativeMultiplication,
+    /// a + 0 = 0 + a = a
+    AdditiveIdentity,
+    /// a * 1 = 1 * a = a
+    MultiplicativeIdentity,
+    /// a + (-a) = (-a) + a = 0
+    AdditiveInverse,
+    /// a * a⁻¹ = a⁻¹ * a = 1
+    MultiplicativeInverse,
+    /// a - b = a + (-b)
+    Subtraction,
+    /// a / b = a * b⁻¹
+    Division,
+    /// a * (b + c) = (a * b) + (a * c)
+    LeftDistributivity,
+    /// (a + b) * c = (a * c) + (b * c)
+    RightDistributivity,
+    /// a * b = 0 implies a = 0 or b = 0
+    NoZeroDivisors,
+    /// 0 ≠ 1
+    NonTrivial,
+    /// a = b * q + r where q = div_euclid(a, b) and r = rem_euclid(a, b)
+    EuclideanDivision,
+    /// rem_euclid(a, b) = 0 or φ(rem_euclid(a, b)) < φ(b)
+    EuclideanRemainder,
+    /// φ(a) ≤ φ(a * b) for non-zero a and b
+    EuclideanMonotonicity,
+}
+
+impl Law {
+    /// Returns the defining equation of the law.
+    pub fn equation(&self) -> &'static str {
+        match self {
+            Law::AddAssign => "a += b leaves a + b in a",
+            Law::MulAssign => "a *= b leaves a * b in a",
+            Law::CommutativeAddition => "a + b = b + a",
+            Law::AssociativeAddition => "(a + b) + c = a + (b + c)",
+            Law::CommutativeMultiplication => "a * b = b * a",
+            Law::AssociativeMultiplication => "(a * b) * c = a * (b * c)",
+            Law::AdditiveIdentity => "a + 0 = 0 + a = a",
+            Law::MultiplicativeIdentity => "a * 1 = 1 * a = a",
+            Law::AdditiveInverse => "a + (-a) = (-a) + a = 0",
+            Law::MultiplicativeInverse => "a * a⁻¹ = a⁻¹ * a = 1",
+            Law::Subtraction => "a - b = a + (-b)",
+            Law::Division => "a / b = a * b⁻¹",
+            Law::LeftDistributivity => "a * (b + c) = (a * b) + (a * c)",
+            Law::RightDistributivity => "(a + b) * c = (a * c) + (b * c)",
+            Law::NoZeroDivisors => "a * b = 0 implies a = 0 or b = 0",
+            Law::NonTrivial => "0 ≠ 1",
+            Law::EuclideanDivision => "a = b * div_euclid(a, b) + rem_euclid(a, b)",
+            Law::EuclideanRemainder => "rem_euclid(a, b) = 0 or φ(rem_euclid(a, b)) < φ(b)",
+            Law::EuclideanMonotonicity => "φ(a) ≤ φ(a * b) for non-zero a and b",
+        }
+    }
+}
+
+impl fmt::Display for Law {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Law::AddAssign => "compound addition",
+            Law::MulAssign => "compound multiplication",
+            Law::CommutativeAddition => "commutativity of addition",
+            Law::AssociativeAddition => "associativity of addition",
+            Law::CommutativeMultiplication => "commutativity of multiplication",
+            Law::AssociativeMultiplication => "associativity of multiplication",
+            Law::AdditiveIdentity => "additive identity",
+            Law::MultiplicativeIdentity => "multiplicative identity",
+            Law::AdditiveInverse => "additive inverse",
+            Law::MultiplicativeInverse => "multiplicative inverse",
+            Law::Subtraction => "subtraction",
+            Law::Division => "division",
+            Law::LeftDistributivity => "left distributivity",
+            Law::RightDistributivity => "right distributivity",
+            Law::NoZeroDivisors => "absence of zero divisors",
+            Law::NonTrivial => "non-triviality",
+            Law::EuclideanDivision => "Euclidean division",
+            Law::EuclideanRemainder => "decrease of the Euclidean function",
+            Law::EuclideanMonotonicity => "monotonicity of the Euclidean function",
+        };
+        write!(f, "{} ({})", name, self.equation())
+    }
+}
+
+/// A counterexample to a law, holding the values `a, b, c` for which the equation fails.
+///
+/// Only the variables which appear in the law are populated; for example the additive identity
+/// law only binds `a`, and `b` and `c` are then `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LawViolation<T> {
+    /// The law which does not hold.
+    pub law: Law,
+    /// The value bound to `a` in the equation.
+    pub a: T,
+    /// The value bound to `b` in the equation, if the law has two or more variables.
+    pub b: Option<T>,
+    /// The value bound to `c` in the equation, if the law has three variables.
+    pub c: Option<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for LawViolation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} fails for a = {:?}", self.law, self.a)?;
+        if let Some(b) = &self.b {
+            write!(f, ", b = {:?}", b)?;
+        }
+        if let Some(c) = &self.c {
+            write!(f, ", c = {:?}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for LawViolation<T> {}
+
+/// A level of the algebraic hierarchy, as checked by the structure checkers.
+///
+/// Each variant lists the axioms which the level adds to the ones below it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Structure {
+    /// [`AdditiveMagma`]: `+=` agrees with `+`
+    AdditiveMagma,
+    /// [`MultiplicativeMagma`]: `*=` agrees with `*`
+    MultiplicativeMagma,
+    /// [`AdditiveSemigroup`]: associative addition
+    AdditiveSemigroup,
+    /// [`MultiplicativeSemigroup`]: associative multiplication
+    MultiplicativeSemigroup,
+    /// [`AdditiveMonoid`]: an additive identity 0
+    AdditiveMonoid,
+    /// [`MultiplicativeMonoid`]: a multiplicative identity 1
+    MultiplicativeMonoid,
+    /// [`AdditiveGroup`]: additive inverses, and subtraction agreeing with them
+    AdditiveGroup,
+    /// [`MultiplicativeGroup`]: multiplicative inverses, and division agreeing with them
+    MultiplicativeGroup,
+    /// [`AdditiveAbelianGroup`]: commutative addition
+    AdditiveAbelianGroup,
+    /// [`MultiplicativeAbelianGroup`]: commutative multiplication
+    MultiplicativeAbelianGroup,
+    /// [`Ring`]: distributivity on both sides
+    Ring,
+    /// [`CommutativeRing`]: commutative multiplication
+    CommutativeRing,
+    /// [`IntegralDomain`]: 0 ≠ 1 and no zero divisors
+    IntegralDomain,
+    /// [`EuclideanDomain`]: Euclidean division and the laws of the Euclidean function
+    EuclideanDomain,
+    /// [`Field`]: inverses and division by non-zero elements
+    Field,
+}
+
+impl fmt::Display for Structure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Structure::AdditiveMagma => "AdditiveMagma",
+            Structure::MultiplicativeMagma => "MultiplicativeMagma",
+            Structure::AdditiveSemigroup => "AdditiveSemigroup",
+            Structure::MultiplicativeSemigroup => "MultiplicativeSemigroup",
+            Structure::AdditiveMonoid => "AdditiveMonoid",
+            Structure::MultiplicativeMonoid => "MultiplicativeMonoid",
+            Structure::AdditiveGroup => "AdditiveGroup",
+            Structure::MultiplicativeGroup => "MultiplicativeGroup",
+            Structure::AdditiveAbelianGroup => "AdditiveAbelianGroup",
+            Structure::MultiplicativeAbelianGroup => "MultiplicativeAbelianGroup",
+            Structure::Ring => "Ring",
+            Structure::CommutativeRing => "CommutativeRing",
+            Structure::IntegralDomain => "IntegralDomain",
+            Structure::EuclideanDomain => "EuclideanDomain",
+            Structure::Field => "Field",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A counterexample to one of the axioms of a structure, tagged with the level of the hierarchy
+/// which introduced the axiom.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxiomViolation<T> {
+    /// The level of the hierarchy whose axiom does not hold.
+    pub structure: Structure,
+    /// The failing law and its counterexample.
+    pub violation: LawViolation<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for AxiomViolation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} is not a valid {}: {}",
+            type_name::<T>(),
+            self.structure,
+            self.violation
+        )
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for AxiomViolation<T> {}
+
+/// Tags the law violations of a check with the structure they belong to.
+fn at_level<T>(
+    structure: Structure,
+    result: Result<(), LawViolation<T>>,
+) -> Result<(), AxiomViolation<T>> {
+    result.map_err(|violation| AxiomViolation {
+        structure,
+        violation,
+    })
+}
+
+/// A source of the values on which laws are checked.
+trait Cases<T> {
+    /// Returns the values to bind to `a` in laws over one variable.
+    fn singles(&mut self) -> impl Iterator<Item = T> + '_;
+
+    /// Returns the values to bind to `a, b` in laws over two variables.
+    fn pairs(&mut self) -> impl Iterator<Item = (T, T)> + '_;
+
+    /// Returns the values to bind to `a, b, c` in laws over three variables.
+    fn triples(&mut self) -> impl Iterator<Item = (T, T, T)> + '_;
+}
+
+/// Draws a fixed number of cases from a generator.
+struct Sampled<G> {
+    gen: G,
+    samples: usize,
+}
+
+impl<T, G: FnMut() -> T> Cases<T> for Sampled<G> {
+    fn singles(&mut self) -> impl Iterator<Item = T> + '_ {
+        std::iter::repeat_with(|| (self.gen)()).take(self.samples)
+    }
+
+    fn pairs(&mut self) -> impl Iterator<Item = (T, T)> + '_ {
+        std::iter::repeat_with(|| ((self.gen)(), (self.gen)())).take(self.samples)
+    }
+
+    fn triples(&mut self) -> impl Iterator<Item = (T, T, T)> + '_ {
+        std::iter::repeat_with(|| ((self.gen)(), (self.gen)(), (self.gen)())).take(self.samples)
+    }
+}
+
+/// Enumerates every case over all the elements of a finite set.
+struct Exhaustive<T> {
+    elements: Vec<T>,
+}
+
+impl<T: Clone> Cases<T> for Exhaustive<T> {
+    fn singles(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.elements.iter().cloned()
+    }
+
+    fn pairs(&mut self) -> impl Iterator<Item = (T, T)> + '_ {
+        let elements = &self.elements;
+        elements
+            .iter()
+            .flat_map(move |a| elements.iter().map(move |b| (a.clone(), b.clone())))
+    }
+
+    fn triples(&mut self) -> impl Iterator<Item = (T, T, T)> + '_ {
+        let elements = &self.elements;
+        elements.iter().flat_map(move |a| {
+            elements.iter().flat_map(move |b| {
+                elements
+                    .iter()
+                    .map(move |c| (a.clone(), b.clone(), c.clone()))
+            })
+        })
+    }
+}
+
+/// Checks a law over one variable on every case.
+fn check_unary<T>(
+    law: Law,
+    cases: &mut impl Cases<T>,
+    holds: impl Fn(&T) -> bool,
+) -> Result<(), LawViolation<T>> {
+    match cases.singles().find(|a| !holds(a)) {
+        Some(a) => Err(LawViolation {
+            law,
+            a,
+            b: None,
+            c: None,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks a law over two variables on every case.
+fn check_binary<T>(
+    law: Law,
+    cases: &mut impl Cases<T>,
+    holds: impl Fn(&T, &T) -> bool,
+) -> Result<(), LawViolation<T>> {
+    match cases.pairs().find(|(a, b)| !holds(a, b)) {
+        Some((a, b)) => Err(LawViolation {
+            law,
+            a,
+            b: Some(b),
+            c: None,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks a law over three variables on every case.
+fn check_ternary<T>(
+    law: Law,
+    cases: &mut impl Cases<T>,
+    holds: impl Fn(&T, &T, &T) -> bool,
+) -> Result<(), LawViolation<T>> {
+    match cases.triples().find(|(a, b, c)| !holds(a, b, c)) {
+        Some((a, b, c)) => Err(LawViolation {
+            law,
+            a,
+            b: Some(b),
+            c: Some(c),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Single laws
+
+fn commutative_addition<T: AdditiveMagma>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), LawViolation<T>> {
+    check_binary(Law::CommutativeAddition, cases, |a, b| {
+        a.clone() + b.clone() == b.clone() + a.clone()
+    })
+}
+
+fn associative_addition<T: AdditiveMagma>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), LawViolation<T>> {
+    check_ternary(Law::AssociativeAddition, cases, |a, b, c| {
+        (a.clone() + b.clone()) + c.clone() == a.clone() + (b.clone() + c.clone())
+    })
+}
+
+fn commutative_multiplication<T: MultiplicativeMagma>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), LawViolation<T>> {
+    check_binary(Law::CommutativeMultiplication, cases, |a, b| {
+        a.clone() * b.clone() == b.clone() * a.clone()
+    })
+}
+
+fn associative_multiplication<T: MultiplicativeMagma>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), LawViolation<T>> {
+    check_ternary(Law::AssociativeMultiplication, cases, |a, b, c| {
+        (a.clone() * b.clone()) * c.clone() == a.clone() * (b.clone() * c.clone())
+    })
+}
+
+fn distributive<T: AdditiveMagma + MultiplicativeMagma>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), LawViolation<T>> {
+    check_ternary(Law::LeftDistributivity, cases, |a, b, c| {
+        a.clone() * (b.clone() + c.clone()) == a.clone() * b.clone() + a.clone() * c.clone()
+    })?;
+    check_ternary(Law::RightDistributivity, cases, |a, b, c| {
+        (a.clone() + b.clone()) * c.clone() == a.clone() * c.clone() + b.clone() * c.clone()
+    })
+}
+
+/// Checks the law promised by [`CommutativeAddition`]: a + b = b + a.
+pub fn check_commutative_addition<T, G>(gen: G, samples: usize) -> Result<(), LawViolation<T>>
+where
+    T: AdditiveMagma + CommutativeAddition,
+    G: FnMut() -> T,
+{
+    commutative_addition(&mut Sampled { gen, samples })
+}
+
+/// Checks the law promised by [`AssociativeAddition`]: (a + b) + c = a + (b + c).
+pub fn check_associative_addition<T, G>(gen: G, samples: usize) -> Result<(), LawViolation<T>>
+where
+    T: AdditiveMagma + AssociativeAddition,
+    G: FnMut() -> T,
+{
+    associative_addition(&mut Sampled { gen, samples })
+}
+
+/// Checks the law promised by [`CommutativeMultiplication`]: a * b = b * a.
+pub fn check_commutative_multiplication<T, G>(gen: G, samples: usize) -> Result<(), LawViolation<T>>
+where
+    T: MultiplicativeMagma + CommutativeMultiplication,
+    G: FnMut() -> T,
+{
+    commutative_multiplication(&mut Sampled { gen, samples })
+}
+
+/// Checks the law promised by [`AssociativeMultiplication`]: (a * b) * c = a * (b * c).
+pub fn check_associative_multiplication<T, G>(gen: G, samples: usize) -> Result<(), LawViolation<T>>
+where
+    T: MultiplicativeMagma + AssociativeMultiplication,
+    G: FnMut() -> T,
+{
+    associative_multiplication(&mut Sampled { gen, samples })
+}
+
+/// Checks the laws promised by [`Distributive`], on both sides:
+/// a * (b + c) = (a * b) + (a * c) and (a + b) * c = (a * c) + (b * c).
+pub fn check_distributive<T, G>(gen: G, samples: usize) -> Result<(), LawViolation<T>>
+where
+    T: AdditiveMagma + MultiplicativeMagma + Distributive,
+    G: FnMut() -> T,
+{
+    distributive(&mut Sampled { gen, samples })
+}
+
+fn no_zero_divisors<T: MultiplicativeMagma + Zero>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), LawViolation<T>> {
+    check_binary(Law::NoZeroDivisors, cases, |a, b| {
+        !(a.clone() * b.clone()).is_zero() || a.is_zero() || b.is_zero()
+    })
+}
+
+/// Checks the law promised by [`NoZeroDivisors`]: a * b = 0 implies a = 0 or b = 0.
+pub fn check_no_zero_divisors<T, G>(gen: G, samples: usize) -> Result<(), LawViolation<T>>
+where
+    T: MultiplicativeMagma + Zero + NoZeroDivisors,
+    G: FnMut() -> T,
+{
+    no_zero_divisors(&mut Sampled { gen, samples })
+}
+
+// Structures
+
+fn additive_magma<T: AdditiveMagma>(cases: &mut impl Cases<T>) -> Result<(), AxiomViolation<T>> {
+    at_level(
+        Structure::AdditiveMagma,
+        check_binary(Law::AddAssign, cases, |a, b| {
+            let mut assigned = a.clone();
+            assigned += b.clone();
+            assigned == a.clone() + b.clone()
+        }),
+    )
+}
+
+fn multiplicative_magma<T: MultiplicativeMagma>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    at_level(
+        Structure::MultiplicativeMagma,
+        check_binary(Law::MulAssign, cases, |a, b| {
+            let mut assigned = a.clone();
+            assigned *= b.clone();
+            assigned == a.clone() * b.clone()
+        }),
+    )
+}
+
+fn additive_semigroup<T: AdditiveSemigroup>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    additive_magma(cases)?;
+    at_level(Structure::AdditiveSemigroup, associative_addition(cases))
+}
+
+fn multiplicative_semigroup<T: MultiplicativeSemigroup>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    multiplicative_magma(cases)?;
+    at_level(
+        Structure::MultiplicativeSemigroup,
+        associative_multiplication(cases),
+    )
+}
+
+fn additive_monoid<T: AdditiveMonoid>(cases: &mut impl Cases<T>) -> Result<(), AxiomViolation<T>> {
+    additive_semigroup(cases)?;
+    at_level(
+        Structure::AdditiveMonoid,
+        check_unary(Law::AdditiveIdentity, cases, |a| {
+            a.clone() + T::zero() == *a && T::zero() + a.clone() == *a
+        }),
+    )
+}
+
+fn multiplicative_monoid<T: MultiplicativeMonoid>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    multiplicative_semigroup(cases)?;
+    at_level(
+        Structure::MultiplicativeMonoid,
+        check_unary(Law::MultiplicativeIdentity, cases, |a| {
+            a.clone() * T::one() == *a && T::one() * a.clone() == *a
+        }),
+    )
+}
+
+fn additive_group<T: AdditiveGroup>(cases: &mut impl Cases<T>) -> Result<(), AxiomViolation<T>> {
+    additive_monoid(cases)?;
+    at_level(
+        Structure::AdditiveGroup,
+        check_unary(Law::AdditiveInverse, cases, |a| {
+            a.clone() + (-a.clone()) == T::zero() && (-a.clone()) + a.clone() == T::zero()
+        }),
+    )?;
+    at_level(
+        Structure::AdditiveGroup,
+        check_binary(Law::Subtraction, cases, |a, b| {
+            a.clone() - b.clone() == a.clone() + (-b.clone())
+        }),
+    )
+}
+
+fn multiplicative_group<T: MultiplicativeGroup>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    multiplicative_monoid(cases)?;
+    at_level(
+        Structure::MultiplicativeGroup,
+        check_unary(Law::MultiplicativeInverse, cases, |a| {
+            a.clone() * a.clone().inv() == T::one() && a.clone().inv() * a.clone() == T::one()
+        }),
+    )?;
+    at_level(
+        Structure::MultiplicativeGroup,
+        check_binary(Law::Division, cases, |a, b| {
+            a.clone() / b.clone() == a.clone() * b.clone().inv()
+        }),
+    )
+}
+
+fn additive_abelian_group<T: AdditiveAbelianGroup>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    additive_group(cases)?;
+    at_level(Structure::AdditiveAbelianGroup, commutative_addition(cases))
+}
+
+fn multiplicative_abelian_group<T: MultiplicativeAbelianGroup>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    multiplicative_group(cases)?;
+    at_level(
+        Structure::MultiplicativeAbelianGroup,
+        commutative_multiplication(cases),
+    )
+}
+
+fn ring<T: Ring>(cases: &mut impl Cases<T>) -> Result<(), AxiomViolation<T>> {
+    additive_abelian_group(cases)?;
+    multiplicative_monoid(cases)?;
+    at_level(Structure::Ring, distributive(cases))
+}
+
+fn commutative_ring<T: CommutativeRing>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    ring(cases)?;
+    at_level(
+        Structure::CommutativeRing,
+        commutative_multiplication(cases),
+    )
+}
+
+fn integral_domain<T: IntegralDomain>(cases: &mut impl Cases<T>) -> Result<(), AxiomViolation<T>> {
+    commutative_ring(cases)?;
+    if T::zero() == T::one() {
+        return Err(AxiomViolation {
+            structure: Structure::IntegralDomain,
+            violation: LawViolation {
+                law: Law::NonTrivial,
+                a: T::zero(),
+                b: None,
+                c: None,
+            },
+        });
+    }
+    at_level(Structure::IntegralDomain, no_zero_divisors(cases))
+}
+
+fn euclidean_domain<T: EuclideanDomain>(
+    cases: &mut impl Cases<T>,
+) -> Result<(), AxiomViolation<T>> {
+    integral_domain(cases)?;
+    at_level(
+        Structure::EuclideanDomain,
+        check_binary(Law::EuclideanDivision, cases, |a, b| {
+            b.is_zero() || {
+                let q = Euclid::div_euclid(a, b);
+                let r = Euclid::rem_euclid(a, b);
+                *a == b.clone() * q + r
+            }
+        }),
+    )?;
+    at_level(
+        Structure::EuclideanDomain,
+        check_binary(Law::EuclideanRemainder, cases, |a, b| {
+            b.is_zero() || {
+                let r = Euclid::rem_euclid(a, b);
+                r.is_zero() || r.euclidean_size() < b.euclidean_size()
+            }
+        }),
+    )?;
+    at_level(
+        Structure::EuclideanDomain,
+        check_binary(Law::EuclideanMonotonicity, cases, |a, b| {
+            a.is_zero()
+                || b.is_zero()
+                || a.euclidean_size() <= (a.clone() * b.clone()).euclidean_size()
+        }),
+    )
+}
+
+fn field<T: Field>(cases: &mut impl Cases<T>) -> Result<(), AxiomViolation<T>> {
+    euclidean_domain(cases)?;
+    at_level(
+        Structure::Field,
+        check_unary(Law::MultiplicativeInverse, cases, |a| {
+            a.is_zero()
+                || (a.clone() * a.clone().inv() == T::one()
+                    && a.clone().inv() * a.clone() == T::one())
+        }),
+    )?;
+    at_level(
+        Structure::Field,
+        check_binary(Law::Division, cases, |a, b| {
+            b.is_zero() || a.clone() / b.clone() == a.clone() * b.clone().inv()
+        }),
+    )
+}
+
+/// Checks the axioms of an [`AdditiveMagma`]: `+=` agrees with `+`.
+pub fn check_additive_magma<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: AdditiveMagma,
+    G: FnMut() -> T,
+{
+    additive_magma(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`MultiplicativeMagma`]: `*=` agrees with `*`.
+pub fn check_multiplicative_magma<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: MultiplicativeMagma,
+    G: FnMut() -> T,
+{
+    multiplicative_magma(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of an [`AdditiveSemigroup`] and the levels below it.
+pub fn check_additive_semigroup<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: AdditiveSemigroup,
+    G: FnMut() -> T,
+{
+    additive_semigroup(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`MultiplicativeSemigroup`] and the levels below it.
+pub fn check_multiplicative_semigroup<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: MultiplicativeSemigroup,
+    G: FnMut() -> T,
+{
+    multiplicative_semigroup(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of an [`AdditiveMonoid`] and the levels below it.
+pub fn check_additive_monoid<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: AdditiveMonoid,
+    G: FnMut() -> T,
+{
+    additive_monoid(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`MultiplicativeMonoid`] and the levels below it.
+pub fn check_multiplicative_monoid<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: MultiplicativeMonoid,
+    G: FnMut() -> T,
+{
+    multiplicative_monoid(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of an [`AdditiveGroup`] and the levels below it.
+pub fn check_additive_group<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: AdditiveGroup,
+    G: FnMut() -> T,
+{
+    additive_group(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`MultiplicativeGroup`] and the levels below it.
+///
+/// Every sample must be invertible; to check the non-zero elements of a field use
+/// [`check_field`] instead.
+pub fn check_multiplicative_group<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: MultiplicativeGroup,
+    G: FnMut() -> T,
+{
+    multiplicative_group(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of an [`AdditiveAbelianGroup`] and the levels below it.
+pub fn check_additive_abelian_group<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: AdditiveAbelianGroup,
+    G: FnMut() -> T,
+{
+    additive_abelian_group(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`MultiplicativeAbelianGroup`] and the levels below it.
+///
+/// Every sample must be invertible; to check the non-zero elements of a field use
+/// [`check_field`] instead.
+pub fn check_multiplicative_abelian_group<T, G>(
+    gen: G,
+    samples: usize,
+) -> Result<(), AxiomViolation<T>>
+where
+    T: MultiplicativeAbelianGroup,
+    G: FnMut() -> T,
+{
+    multiplicative_abelian_group(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`Ring`] and the levels below it.
+pub fn check_ring<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: Ring,
+    G: FnMut() -> T,
+{
+    ring(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`CommutativeRing`] and the levels below it.
+pub fn check_commutative_ring<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: CommutativeRing,
+    G: FnMut() -> T,
+{
+    commutative_ring(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of an [`IntegralDomain`] and the levels below it.
+pub fn check_integral_domain<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: IntegralDomain,
+    G: FnMut() -> T,
+{
+    integral_domain(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`EuclideanDomain`] and the levels below it.
+///
+/// Pairs with a zero divisor `b` are skipped when checking Euclidean division and the decrease of
+/// the Euclidean function, and pairs with a zero operand when checking its monotonicity.
+pub fn check_euclidean_domain<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: EuclideanDomain,
+    G: FnMut() -> T,
+{
+    euclidean_domain(&mut Sampled { gen, samples })
+}
+
+/// Checks the axioms of a [`Field`] and the levels below it.
+///
+/// Multiplicative inverses and division are only checked for non-zero samples.
+pub fn check_field<T, G>(gen: G, samples: usize) -> Result<(), AxiomViolation<T>>
+where
+    T: Field,
+    G: FnMut() -> T,
+{
+    field(&mut Sampled { gen, samples })
+}
+
+// Exhaustive verification
+//
+// For small finite structures every law is checked over the full Cayley tables, turning the
+// evidence of the sampled checkers into a proof. Laws over three variables visit |T|³ cases.
+
+/// Verifies every axiom of a [`Ring`] on all elements of a finite set.
+pub fn check_ring_exhaustive<T>() -> Result<(), AxiomViolation<T>>
+where
+    T: Ring + FiniteSet,
+{
+    ring(&mut Exhaustive {
+        elements: T::elements().collect(),
+    })
+}
+
+/// Verifies every axiom of a [`CommutativeRing`] on all elements of a finite set.
+pub fn check_commutative_ring_exhaustive<T>() -> Result<(), AxiomViolation<T>>
+where
+    T: CommutativeRing + FiniteSet,
+{
+    commutative_ring(&mut Exhaustive {
+        elements: T::elements().collect(),
+    })
+}
+
+/// Verifies every axiom of a [`FiniteField`] on all of its elements.
+///
+/// # Panics
+/// Panics if [`FiniteSet::elements`] does not enumerate exactly [`FiniteField::order`] distinct
+/// elements, in which case the verification would not cover the whole field.
+pub fn check_field_exhaustive<T>() -> Result<(), AxiomViolation<T>>
+where
+    T: FiniteField + FiniteSet,
+{
+    let elements: Vec<T> = T::elements().collect();
+    let count = elements.iter().fold(T::ScalarType::zero(), |count, _| {
+        count + T::ScalarType::one()
+    });
+    assert!(
+        count == T::order(),
+        "the elements of {} do not match the order of the field",
+        type_name::<T>()
+    );
+    assert!(
+        elements
+            .iter()
+            .enumerate()
+            .all(|(i, a)| !elements[..i].contains(a)),
+        "the elements of {} are not distinct",
+        type_name::<T>()
+    );
+    field(&mut Exhaustive { elements })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Checked, EuclideanValuation, Fp, Zn};
+    use std::num::Wrapping;
+    use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+    /// Returns a generator cycling through `values`.
+    fn cycle<T: Clone>(values: &[T]) -> impl FnMut() -> T + '_ {
+        let mut i = 0;
+        move || {
+            i += 1;
+            values[(i * 7) % values.len()].clone()
+        }
+    }
+
+    /// Saturating addition, which is commutative but not associative.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Saturating(i8);
+
+    impl Add for Saturating {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Saturating(self.0.saturating_add(rhs.0))
+        }
+    }
+
+    impl AddAssign for Saturating {
+        fn add_assign(&mut self, rhs: Self) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl CommutativeAddition for Saturating {}
+    impl AssociativeAddition for Saturating {}
+
+    /// The integers with a constant Euclidean function, under which remainders do not decrease.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct ConstantSize(Checked<i64>);
+
+    impl Add for ConstantSize {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            ConstantSize(self.0 + rhs.0)
+        }
+    }
+
+    impl AddAssign for ConstantSize {
+        fn add_assign(&mut self, rhs: Self) {
+            self.0 += rhs.0;
+        }
+    }
+
+    impl Sub for ConstantSize {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            ConstantSize(self.0 - rhs.0)
+        }
+    }
+
+    impl SubAssign for ConstantSize {
+        fn sub_assign(&mut self, rhs: Self) {
+            self.0 -= rhs.0;
+        }
+    }
+
+    impl Neg for ConstantSize {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            ConstantSize(-self.0)
+        }
+    }
+
+    impl Mul for ConstantSize {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            ConstantSize(self.0 * rhs.0)
+        }
+    }
+
+    impl MulAssign for ConstantSize {
+        fn mul_assign(&mut self, rhs: Self) {
+            self.0 *= rhs.0;
+        }
+    }
+
+    impl Zero for ConstantSize {
+        fn zero() -> Self {
+            ConstantSize(Checked(0))
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0.is_zero()
+        }
+    }
+
+    impl One for ConstantSize {
+        fn one() -> Self {
+            ConstantSize(Checked(1))
+        }
+    }
+
+    impl Div for ConstantSize {
+        type Output = Self;
+
+        fn div(self, rhs: Self) -> Self {
+            ConstantSize(self.0 / rhs.0)
+        }
+    }
+
+    impl Rem for ConstantSize {
+        type Output = Self;
+
+        fn rem(self, rhs: Self) -> Self {
+            ConstantSize(self.0 % rhs.0)
+        }
+    }
+
+    impl Euclid for ConstantSize {
+        fn div_euclid(&self, v: &Self) -> Self {
+            ConstantSize(Euclid::div_euclid(&self.0, &v.0))
+        }
+
+        fn rem_euclid(&self, v: &Self) -> Self {
+            ConstantSize(Euclid::rem_euclid(&self.0, &v.0))
+        }
+    }
+
+    impl EuclideanValuation for ConstantSize {
+        fn euclidean_size(&self) -> u128 {
+            1
+        }
+    }
+
+    impl CommutativeAddition for ConstantSize {}
+    impl AssociativeAddition for ConstantSize {}
+    impl CommutativeMultiplication for ConstantSize {}
+    impl AssociativeMultiplication for ConstantSize {}
+    impl Distributive for ConstantSize {}
+    impl NoZeroDivisors for ConstantSize {}
+
+    /// Arithmetic modulo 2⁸, falsely promising the absence of zero divisors.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Mod256(Wrapping<u8>);
+
+    impl Add for Mod256 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Mod256(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul for Mod256 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Mod256(self.0 * rhs.0)
+        }
+    }
+
+    impl MulAssign for Mod256 {
+        fn mul_assign(&mut self, rhs: Self) {
+            self.0 *= rhs.0;
+        }
+    }
+
+    impl Zero for Mod256 {
+        fn zero() -> Self {
+            Mod256(Wrapping(0))
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 .0 == 0
+        }
+    }
+
+    impl NoZeroDivisors for Mod256 {}
+
+    #[test]
+    fn lawful_types_pass() {
+        let integers: Vec<_> = (-20..=20).map(Checked).collect();
+        check_euclidean_domain(cycle(&integers), 500).unwrap();
+        check_no_zero_divisors(cycle(&integers), 500).unwrap();
+
+        let residues: Vec<_> = (0..101).map(Fp::<101>::new).collect();
+        check_field(cycle(&residues), 500).unwrap();
+        check_field_exhaustive::<Fp<7>>().unwrap();
+        check_commutative_ring_exhaustive::<Zn<12>>().unwrap();
+    }
+
+    #[test]
+    fn non_associative_addition_is_caught() {
+        let values = [Saturating(100), Saturating(-100), Saturating(50)];
+        let violation = check_associative_addition(cycle(&values), 100).unwrap_err();
+        assert_eq!(violation.law, Law::AssociativeAddition);
+        let (a, b, c) = (violation.a, violation.b.unwrap(), violation.c.unwrap());
+        assert_ne!((a + b) + c, a + (b + c));
+
+        check_commutative_addition(cycle(&values), 100).unwrap();
+        check_additive_magma(cycle(&values), 100).unwrap();
+    }
+
+    #[test]
+    fn constant_euclidean_function_is_caught() {
+        let values: Vec<_> = (1..=9).map(|n| ConstantSize(Checked(n))).collect();
+        let violation = check_euclidean_domain(cycle(&values), 100).unwrap_err();
+        assert_eq!(violation.structure, Structure::EuclideanDomain);
+        assert_eq!(violation.violation.law, Law::EuclideanRemainder);
+        let b = violation.violation.b.unwrap();
+        assert!(!Euclid::rem_euclid(&violation.violation.a, &b).is_zero());
+
+        check_integral_domain(cycle(&values), 100).unwrap();
+    }
+
+    #[test]
+    fn zero_divisors_are_caught() {
+        let values = [
+            Mod256(Wrapping(3)),
+            Mod256(Wrapping(16)),
+            Mod256(Wrapping(16)),
+        ];
+        let violation = check_no_zero_divisors(cycle(&values), 100).unwrap_err();
+        assert_eq!(violation.law, Law::NoZeroDivisors);
+        assert_eq!(violation.a, Mod256(Wrapping(16)));
+        assert_eq!(violation.b, Some(Mod256(Wrapping(16))));
+    }
+
+    #[test]
+    fn violations_name_the_structure_and_law() {
+        let violation = AxiomViolation {
+            structure: Structure::Ring,
+            violation: LawViolation {
+                law: Law::LeftDistributivity,
+                a: Checked(1i8),
+                b: Some(Checked(2)),
+                c: None,
+            },
+        };
+        assert_eq!(
+            violation.to_string(),
+            "noether::primitive::Checked<i8> is not a valid Ring: left distributivity \
+             (a * (b + c) = (a * b) + (a * c)) fails for a = Checked(1), b = Checked(2)"
+        );
+    }
+}
@@ -3,6 +3,42 @@ use std::ops::{
     Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
 };
 
+mod binary_field;
+mod dyn_prime_field;
+pub mod euclidean;
+mod exponentiation;
+mod extension;
+pub mod laws;
+pub mod ntt;
+mod polynomial;
+mod prime_field;
+mod primitive;
+mod product;
+mod uint;
+mod zn;
+
+pub use binary_field::{BinaryField, Gf256, Gf2_128};
+pub use dyn_prime_field::{DynPrimeField, ModulusError, PrimeFieldContext};
+pub use extension::{CubicExtension, CubicNonResidue, QuadraticExtension, QuadraticNonResidue};
+pub use polynomial::{
+    DensePoly, GrLex, GrevLex, GroebnerBasis, Lex, MPoly, Monomial, MonomialOrder, SparsePoly,
+};
+pub use prime_field::{FinitePrimeField, PrimeFieldParams};
+pub use primitive::Checked;
+pub use product::DirectProduct;
+pub use uint::{BigUInt, UInt};
+pub use zn::{Fp, Zn};
+
+// Re-exported so that code generated by the derive macros can name its traits.
+pub use num_traits;
+
+#[cfg(feature = "derive")]
+pub use noether_derive::{
+    AdditiveAbelianGroup, AssociativeAddition, AssociativeMultiplication, CommutativeAddition,
+    CommutativeMultiplication, CommutativeRing, ComponentWise, Distributive, Field, NoZeroDivisors,
+    Ring,
+};
+
 // A note on the reasons why certain traits are used:
 //
 // The `Inv` trait is the multiplicative inverse operation.
@@ -10,9 +46,9 @@ use std::ops::{
 // The `Zero` trait is the additive identity operation.
 // The `Neg` trait is the additive inverse operation.
 
-// TODO(These marker traits could actually mean something and check things)
-
 // Marker traits for algebraic properties
+//
+// These are promises made by the implementor; the `laws` module can check them on sample values.
 
 /// Marker trait for commutative addition: a + b = b + a
 pub trait CommutativeAddition {}
@@ -21,7 +57,13 @@ pub trait CommutativeAddition {}
 pub trait CommutativeMultiplication {}
 
 /// Marker trait for associative addition: (a + b) + c = a + (b + c)
-pub trait AssociativeAddition {}
+pub trait AssociativeAddition {
+    /// Whether a sum can leave the range of the type, as with [`Checked`] integers, which panic
+    /// instead. The law then only holds when every partial sum is in range: (a + b) + c may panic
+    /// where a + (b + c) does not. Algorithms which regroup sums, such as Karatsuba
+    /// multiplication of polynomials, fall back to the order of the definition for such types.
+    const BOUNDED: bool = false;
+}
 
 /// Marker trait for associative multiplication: (a * b) * c = a * (b * c)
 pub trait AssociativeMultiplication {}
@@ -29,6 +71,9 @@ pub trait AssociativeMultiplication {}
 /// Marker trait for distributive multiplication over addition: a * (b + c) = (a * b) + (a * c)
 pub trait Distributive {}
 
+/// Marker trait for the absence of zero divisors: a * b = 0 implies a = 0 or b = 0
+pub trait NoZeroDivisors {}
+
 /// Trait for closed addition operation.
 pub trait ClosedAdd<Rhs = Self>: Add<Rhs, Output = Self> {}
 
@@ -145,6 +190,23 @@ where
 /// 10. Choice: ∀A(∅ ∉ A → ∃f:A → ∪A ∀B∈A(f(B) ∈ B))
 pub trait Set: Sized + Clone + PartialEq {}
 
+/// Represents a Finite Set, a set whose elements can be enumerated.
+///
+/// # Mathematical Definition
+/// A set A is finite if there exists a bijection f: {1, ..., n} → A for some n ∈ ℕ₀,
+/// in which case |A| = n.
+///
+/// # Properties
+/// - Enumeration: Every element of A is produced exactly once by [`FiniteSet::elements`]
+///
+/// [`elements`](FiniteSet::elements) returns `impl Iterator` so that implementations can return
+/// closures and adapters without naming or boxing them. Return-position `impl Trait` in traits
+/// is stable since Rust 1.75, which makes 1.75 the minimum supported Rust version of the crate.
+pub trait FiniteSet: Set {
+    /// Returns an iterator over every element of the set, each produced exactly once.
+    fn elements() -> impl Iterator<Item = Self>;
+}
+
 /// Represents an Additive Magma, an algebraic structure with a set and a closed addition operation.
 ///
 /// # Mathematical Definition
@@ -218,6 +280,68 @@ pub trait MultiplicativeSemigroup: MultiplicativeMagma + AssociativeMultiplicati
 /// - Identity: There exists an element 0 in M such that for every element a in M, a + 0 = 0 + a = a
 pub trait AdditiveMonoid: AdditiveSemigroup + ClosedZero {}
 
+/// Selection between two values without branching on the choice, for computations whose
+/// sequence of operations must not depend on secret data.
+///
+/// Implementations combine the representations of both values under a mask derived from the
+/// choice, as in (a ∧ ¬m) ∨ (b ∧ m) with m = 0 - choice. They may branch on anything else,
+/// such as the length or kind of a representation, but never on the choice.
+pub trait ConditionallySelectable: Clone {
+    /// Returns `a` if `choice` is 0 and `b` if it is 1. Other choices are not allowed.
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self;
+
+    /// Swaps `a` and `b` if `choice` is 1, and leaves them if it is 0.
+    fn conditional_swap(a: &mut Self, b: &mut Self, choice: u64) {
+        let first = Self::conditional_select(a, b, choice);
+        *b = Self::conditional_select(b, a, choice);
+        *a = first;
+    }
+}
+
+/// Multiplication of the elements of an additive monoid by non-negative integers.
+///
+/// # Mathematical Definition
+/// For a ∈ M and n ∈ ℕ₀, n · a = a + ... + a (n times), with 0 · a = 0.
+///
+/// The integer n is given as little-endian 64-bit limbs, so scalars of any width can be used,
+/// such as the limbs of a [`UInt`]. Each method performs O(log n) additions; they differ in how
+/// many, and in whether the sequence of operations depends on n.
+///
+/// # Example
+/// ```
+/// use noether::{ScalarMultiplication, Zn};
+///
+/// let a = Zn::<101>::new(7);
+/// assert_eq!(a.double_and_add(&[1000]), Zn::new(7000));
+/// assert_eq!(a.sliding_window_mul(&[1000]), a.montgomery_ladder_mul(&[1000]));
+/// ```
+pub trait ScalarMultiplication: AdditiveMonoid {
+    /// Returns n · self by double-and-add, with one doubling per bit of n and one addition per
+    /// set bit.
+    fn double_and_add(&self, n: &[u64]) -> Self {
+        exponentiation::binary(self, n, Self::zero(), |a, b| a.clone() + b.clone())
+    }
+
+    /// Returns n · self by the sliding-window method, which precomputes a few odd multiples of
+    /// `self` to replace most of the additions of [`double_and_add`](Self::double_and_add).
+    fn sliding_window_mul(&self, n: &[u64]) -> Self {
+        exponentiation::sliding_window(self, n, Self::zero(), |a, b| a.clone() + b.clone())
+    }
+
+    /// Returns n · self by the Montgomery ladder, which performs one addition and one doubling
+    /// for every bit of the limbs of n, leading zeros included.
+    ///
+    /// The sequence of operations only depends on the number of limbs, not on their value: the
+    /// bits of n only enter [`ConditionallySelectable::conditional_swap`] as masks, so the
+    /// ladder runs in constant time whenever the addition of `Self` does.
+    fn montgomery_ladder_mul(&self, n: &[u64]) -> Self
+    where
+        Self: ConditionallySelectable,
+    {
+        exponentiation::montgomery_ladder(self, n, Self::zero(), |a, b| a.clone() + b.clone())
+    }
+}
+
 /// Represents a Multiplicative Monoid, an algebraic structure with an associative multiplication operation and an identity element.
 ///
 /// # Mathematical Definition
@@ -233,6 +357,54 @@ pub trait AdditiveMonoid: AdditiveSemigroup + ClosedZero {}
 /// - Identity: There exists an element 1 in M such that for every element a in M, a * 1 = 1 * a = a
 pub trait MultiplicativeMonoid: MultiplicativeSemigroup + ClosedOne {}
 
+/// Exponentiation of the elements of a multiplicative monoid by non-negative integers.
+///
+/// # Mathematical Definition
+/// For a ∈ M and n ∈ ℕ₀, aⁿ = a * ... * a (n times), with a⁰ = 1.
+///
+/// The exponent is given as little-endian 64-bit limbs, so exponents of any width can be used,
+/// such as the limbs of a [`UInt`]. Each method performs O(log n) multiplications; they differ in
+/// how many, and in whether the sequence of operations depends on the exponent.
+///
+/// # Example
+/// ```
+/// use noether::{Exponentiation, Fp};
+///
+/// // Fermat's little theorem: aᵖ⁻¹ = 1.
+/// let a = Fp::<65537>::new(3);
+/// assert_eq!(a.square_and_multiply(&[65536]), Fp::new(1));
+///
+/// // a^(2⁶⁴) has an exponent of two limbs.
+/// assert_eq!(a.sliding_window_pow(&[0, 1]), a.montgomery_ladder_pow(&[0, 1]));
+/// ```
+pub trait Exponentiation: MultiplicativeMonoid {
+    /// Returns selfⁿ by square-and-multiply, with one squaring per bit of n and one
+    /// multiplication per set bit.
+    fn square_and_multiply(&self, n: &[u64]) -> Self {
+        exponentiation::binary(self, n, Self::one(), |a, b| a.clone() * b.clone())
+    }
+
+    /// Returns selfⁿ by the sliding-window method, which precomputes a few odd powers of `self`
+    /// to replace most of the multiplications of
+    /// [`square_and_multiply`](Self::square_and_multiply).
+    fn sliding_window_pow(&self, n: &[u64]) -> Self {
+        exponentiation::sliding_window(self, n, Self::one(), |a, b| a.clone() * b.clone())
+    }
+
+    /// Returns selfⁿ by the Montgomery ladder, which performs one multiplication and one
+    /// squaring for every bit of the limbs of n, leading zeros included.
+    ///
+    /// The sequence of operations only depends on the number of limbs, not on their value: the
+    /// bits of n only enter [`ConditionallySelectable::conditional_swap`] as masks, so the
+    /// ladder runs in constant time whenever the multiplication of `Self` does.
+    fn montgomery_ladder_pow(&self, n: &[u64]) -> Self
+    where
+        Self: ConditionallySelectable,
+    {
+        exponentiation::montgomery_ladder(self, n, Self::one(), |a, b| a.clone() * b.clone())
+    }
+}
+
 /// Represents an Additive Group, an algebraic structure with an associative addition operation, an identity element, and inverses.
 ///
 /// # Mathematical Definition
@@ -324,6 +496,26 @@ pub trait MultiplicativeAbelianGroup: MultiplicativeGroup + CommutativeMultiplic
 ///    b. ∀ a, b, c ∈ R, (a + b) · c = (a · c) + (b · c) (right distributivity)
 pub trait Ring: AdditiveAbelianGroup + MultiplicativeMonoid + Distributive {}
 
+/// Represents exact division by small positive integers in a ring.
+///
+/// # Mathematical Definition
+/// The exact quotient of a by a positive integer n is the q with n · q = a, where n · q is the
+/// sum of n copies of q. It exists for every a when n · 1 is a unit, as in a field whose
+/// characteristic does not divide n, and it is unique whenever n · 1 is not a zero divisor, as
+/// in the integers.
+///
+/// [`DensePoly::mul_toom3`] interpolates with exact quotients by 2 and 3 for Toom-3,
+/// and uses it only for rings that provide them. The default [`div_exact`](Self::div_exact)
+/// provides none, which is the impl for rings where 2 · 1 or 3 · 1 is a zero divisor, such as
+/// `Wrapping<u8>` and the binary fields, and for the floats, whose division is not exact.
+pub trait ExactDivision: Ring {
+    /// Returns the unique q with n · q = `self`, or `None` if there is none or the ring does not
+    /// provide it.
+    fn div_exact(&self, _n: u32) -> Option<Self> {
+        None
+    }
+}
+
 /// Represents a Commutative Ring, an algebraic structure where multiplication is commutative.
 ///
 /// # Mathematical Definition
@@ -349,7 +541,11 @@ pub trait CommutativeRing: Ring + CommutativeMultiplication {}
 ///    ∀ a, b ∈ D, if a · b = 0, then a = 0 or b = 0
 /// 3. The zero element is distinct from the unity:
 ///    0 ≠ 1
-pub trait IntegralDomain: CommutativeRing {}
+///
+/// The absence of zero divisors is promised by [`NoZeroDivisors`]; a commutative ring which does
+/// not make that promise, such as a [`DirectProduct`] or `Wrapping<u8>`, is not an integral
+/// domain.
+pub trait IntegralDomain: CommutativeRing + NoZeroDivisors {}
 
 /// Represents a Unique Factorization Domain (UFD), an integral domain where every non-zero
 /// non-unit element has a unique factorization into irreducible elements.
@@ -389,7 +585,25 @@ pub trait PrincipalIdealDomain: UniqueFactorizationDomain {}
 /// Let (R, +, ·) be an integral domain and φ: R\{0} → ℕ₀ a function. R is a Euclidean domain if:
 /// 1. ∀a, b ∈ R, b ≠ 0, ∃!q, r ∈ R : a = bq + r ∧ (r = 0 ∨ φ(r) < φ(b)) (Division with Remainder)
 /// 2. ∀a, b ∈ R\{0} : φ(a) ≤ φ(ab) (Multiplicative Property)
-pub trait EuclideanDomain: PrincipalIdealDomain + Euclid {}
+///
+/// The division is given by [`Euclid`], and φ by [`EuclideanValuation`].
+pub trait EuclideanDomain: PrincipalIdealDomain + Euclid + EuclideanValuation {}
+
+/// The Euclidean function φ of a Euclidean domain.
+///
+/// # Mathematical Definition
+/// φ: R\{0} → ℕ₀ measures the size of an element such that Euclidean division makes progress:
+/// 1. ∀a, b ∈ R, b ≠ 0 : rem_euclid(a, b) = 0 ∨ φ(rem_euclid(a, b)) < φ(b)
+/// 2. ∀a, b ∈ R\{0} : φ(a) ≤ φ(ab)
+///
+/// φ is not unique: the absolute value and its bit length are both Euclidean functions on ℤ.
+/// Implementations choose the one matching their [`Euclid`] impl, such as the absolute value for
+/// integers, the degree for polynomials, and 0 for zero and 1 otherwise in a field. The value
+/// at zero is unconstrained, and is 0 in this crate.
+pub trait EuclideanValuation {
+    /// Returns φ(self).
+    fn euclidean_size(&self) -> u128;
+}
 
 /// Represents a Field, a commutative ring where every non-zero element has a multiplicative inverse.
 ///
@@ -403,6 +617,64 @@ pub trait EuclideanDomain: PrincipalIdealDomain + Euclid {}
 /// 2. ∀ a ∈ F, a ≠ 0, ∃ a⁻¹ ∈ F, a · a⁻¹ = a⁻¹ · a = 1 (multiplicative inverse)
 pub trait Field: EuclideanDomain + MultiplicativeAbelianGroup {}
 
+/// Inversion of many field elements at the cost of one inversion, by Montgomery's trick.
+///
+/// # Mathematical Definition
+/// With the prefix products pᵢ = a₀ · ... · aᵢ, a single inversion gives pₙ₋₁⁻¹, and walking back
+/// from the end
+///
+/// aᵢ⁻¹ = pᵢ₋₁ · pᵢ⁻¹ and pᵢ₋₁⁻¹ = aᵢ · pᵢ⁻¹
+///
+/// recovers every inverse with 3(n - 1) multiplications in total.
+///
+/// # Example
+/// ```
+/// use noether::{BatchInversion, Fp};
+/// use num_traits::Inv;
+///
+/// let mut values: Vec<Fp<101>> = (1..=5).map(Fp::new).collect();
+/// let expected: Vec<Fp<101>> = values.iter().map(|a| a.inv()).collect();
+/// Fp::batch_invert(&mut values);
+/// assert_eq!(values, expected);
+/// ```
+pub trait BatchInversion: Field {
+    /// Replaces every element of `values` by its inverse.
+    ///
+    /// # Panics
+    /// Panics if one of the elements is zero, leaving `values` unchanged.
+    fn batch_invert(values: &mut [Self]) {
+        assert!(
+            !values.iter().any(Self::is_zero),
+            "attempt to invert zero in batch_invert"
+        );
+        Self::batch_invert_skipping_zeros(values);
+    }
+
+    /// Replaces every non-zero element of `values` by its inverse, and leaves the zeros as they
+    /// are.
+    fn batch_invert_skipping_zeros(values: &mut [Self]) {
+        // prefixes[i] is the product of the non-zero elements before the i-th of them.
+        let mut prefixes = Vec::with_capacity(values.len());
+        let mut product = Self::one();
+        for value in values.iter().filter(|value| !value.is_zero()) {
+            prefixes.push(product.clone());
+            product *= value.clone();
+        }
+
+        // The inverse of the product of the non-zero elements up to the current one.
+        let mut inverse = product.inv();
+        for (value, prefix) in values
+            .iter_mut()
+            .rev()
+            .filter(|value| !value.is_zero())
+            .zip(prefixes.into_iter().rev())
+        {
+            let value_inverse = inverse.clone() * prefix;
+            inverse *= std::mem::replace(value, value_inverse);
+        }
+    }
+}
+
 /// Represents a Finite Field, a field with a finite number of elements.
 ///
 /// # Mathematical Definition
@@ -410,15 +682,121 @@ pub trait Field: EuclideanDomain + MultiplicativeAbelianGroup {}
 ///
 /// # Properties
 /// - The number of elements is always a prime power p^n
+/// - The characteristic is the prime p, and n is the degree of the field over its prime subfield
 pub trait FiniteField: Field {
-    ///Allows for an arbitrary size representation without specificing type
-    type ScalarType: Clone + PartialOrd + Zero + One;
+    /// The integer type in which the characteristic and order are expressed, such as `u64`,
+    /// [`UInt`] for prime fields too large for a primitive integer, or [`BigUInt`] for
+    /// extensions. It must hold the order pⁿ, not only the characteristic p, and converts into
+    /// [`BigUInt`] so that orders of different fields can be combined exactly.
+    type ScalarType: Clone + PartialOrd + Zero + One + Into<BigUInt>;
+
+    /// Returns the characteristic p of the field.
+    fn characteristic() -> Self::ScalarType;
+
+    /// Returns the number of elements p^n in the field.
+    fn order() -> Self::ScalarType;
+
+    /// Returns the degree n of the field over its prime subfield 𝔽ₚ.
+    fn extension_degree() -> u32;
+
+    /// Applies φᵖᵒʷᵉʳ, where φ(a) = aᵖ is the Frobenius automorphism over 𝔽ₚ.
+    ///
+    /// φ generates the Galois group of the field over its prime subfield, so `power` is taken
+    /// modulo n. The default raises to the power p `power` times; prime fields override it with
+    /// the identity, and extensions with one multiplication per coefficient.
+    ///
+    /// This is the Frobenius over 𝔽ₚ, whatever the base field; the Frobenius of an extension
+    /// relative to its base field is [`FieldExtension::frobenius_map`].
+    fn absolute_frobenius_map(&self, power: usize) -> Self {
+        let p: BigUInt = Self::characteristic().into();
+        (0..power % Self::extension_degree() as usize)
+            .fold(self.clone(), |a, _| a.square_and_multiply(p.as_limbs()))
+    }
+}
+
+/// Represents a Finite Field whose size may only be known at runtime.
+///
+/// This is the instance-level counterpart of [`FiniteField`], for field types such as
+/// [`DynPrimeField`] whose modulus is carried by each element rather than by the type. Every
+/// [`FiniteField`] is a `DynFiniteField` through a blanket impl.
+///
+/// # Properties
+/// - Elements of the same field report the same characteristic and order
+pub trait DynFiniteField: Field {
+    /// The integer type in which the characteristic and order are expressed, which must hold the
+    /// order.
+    type ScalarType: Clone + PartialOrd + Zero + One + Into<BigUInt>;
+
+    /// Returns the characteristic of the field containing `self`.
+    fn field_characteristic(&self) -> Self::ScalarType;
+
+    /// Returns the number of elements in the field containing `self`.
+    fn field_order(&self) -> Self::ScalarType;
 
-    /// Returns the characteristic of the field.
-    fn characteristic() -> u64;
+    /// Returns the degree of the field containing `self` over its prime subfield.
+    fn field_extension_degree(&self) -> u32;
+}
+
+/// Represents a Finite Field whose multiplicative group has a large subgroup of power-of-two
+/// order, as needed by the number-theoretic transforms of the [`ntt`] module.
+///
+/// # Mathematical Definition
+/// The multiplicative group of a finite field of order q is cyclic of order q - 1. Writing
+/// q - 1 = 2ˢ · t with t odd, the two-adicity of the field is s, and for every k ≤ s the field
+/// contains a primitive 2ᵏ-th root of unity: an element ω with ω^(2ᵏ) = 1 and ω^(2ᵏ⁻¹) ≠ 1.
+///
+/// # Properties
+/// - If ω is a primitive 2ˢ-th root of unity, then ω^(2ˢ⁻ᵏ) is a primitive 2ᵏ-th root of unity
+/// - If q - 1 = 2ˢ · bʲ · t for an odd prime b, the field also contains primitive roots of unity
+///   of every order 2ᵏ · bⁱ with k ≤ s and i ≤ j, which allow mixed-radix transforms
+pub trait TwoAdicField: FiniteField {
+    /// The two-adicity s, the largest integer such that 2ˢ divides q - 1.
+    const TWO_ADICITY: u32;
+
+    /// An odd prime b and the largest j such that bʲ divides q - 1, when the field supports
+    /// mixed-radix transforms with radix b.
+    const SMALL_SUBGROUP_BASE: Option<(u64, u32)> = None;
+
+    /// Returns a primitive 2ˢ-th root of unity, where s is [`TWO_ADICITY`](Self::TWO_ADICITY).
+    fn two_adic_root_of_unity() -> Self;
+
+    /// Returns a primitive root of unity of order 2ˢ · bʲ, where (b, j) is
+    /// [`SMALL_SUBGROUP_BASE`](Self::SMALL_SUBGROUP_BASE), or `None` without a small subgroup.
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        None
+    }
 
-    /// Returns the number of elements in the field.
-    fn order() -> u64;
+    /// Returns a primitive n-th root of unity, or `None` if n is not of the form 2ᵏ or 2ᵏ · bⁱ
+    /// supported by the field.
+    fn root_of_unity(n: u64) -> Option<Self> {
+        if n == 0 || n.trailing_zeros() > Self::TWO_ADICITY {
+            return None;
+        }
+        let k = n.trailing_zeros();
+        let odd = n >> k;
+        let mut root = if odd == 1 {
+            Self::two_adic_root_of_unity()
+        } else {
+            let (base, adicity) = Self::SMALL_SUBGROUP_BASE?;
+            let (mut rest, mut i) = (odd, 0);
+            while rest % base == 0 {
+                rest /= base;
+                i += 1;
+            }
+            if rest != 1 || i > adicity {
+                return None;
+            }
+            let mut root = Self::large_subgroup_root_of_unity()?;
+            for _ in i..adicity {
+                root = (1..base).fold(root.clone(), |power, _| power * root.clone());
+            }
+            root
+        };
+        for _ in k..Self::TWO_ADICITY {
+            root = root.clone() * root;
+        }
+        Some(root)
+    }
 }
 
 /// Represents an Ordered Field, a field with a total order compatible with its operations.
@@ -486,6 +864,8 @@ pub trait VectorSpace: AdditiveAbelianGroup {
 /// - L is a field
 /// - K is a subfield of L
 /// - L is a vector space over K
+/// - If L/K is Galois of degree n with cyclic Galois group ⟨σ⟩, as for every extension of finite
+///   fields, then Tr(a) = a + σ(a) + ... + σⁿ⁻¹(a) and N(a) = a · σ(a) ⋯ σⁿ⁻¹(a)
 pub trait FieldExtension: Field + VectorSpace<Scalar = Self::BaseField> {
     /// The base field of this extension.
     type BaseField: Field;
@@ -493,11 +873,40 @@ pub trait FieldExtension: Field + VectorSpace<Scalar = Self::BaseField> {
     /// Returns the degree of the field extension.
     fn degree() -> usize;
 
-    /// Computes the trace of an element.
-    fn trace(&self) -> Self::BaseField;
+    /// Returns the element as an element of the base field, or `None` if it does not lie in it.
+    fn to_base_field(&self) -> Option<Self::BaseField>;
+
+    /// Applies σᵖᵒʷᵉʳ, where σ is the Frobenius automorphism generating Gal(L/K).
+    ///
+    /// For finite fields σ(a) = a^q, where q is the order of K. This is the Frobenius relative to
+    /// K, which only agrees with the p-power [`FiniteField::absolute_frobenius_map`] when K is the prime
+    /// field. Since σⁿ is the identity, `power` is taken modulo the degree.
+    fn frobenius_map(&self, power: usize) -> Self;
+
+    /// Returns the Galois conjugates a, σ(a), ..., σⁿ⁻¹(a) of an element.
+    fn conjugates(&self) -> Vec<Self> {
+        (0..Self::degree())
+            .map(|power| self.frobenius_map(power))
+            .collect()
+    }
+
+    /// Computes the trace of an element, by default the sum of its conjugates.
+    fn trace(&self) -> Self::BaseField {
+        self.conjugates()
+            .into_iter()
+            .fold(Self::zero(), |sum, conjugate| sum + conjugate)
+            .to_base_field()
+            .expect("the trace of an element lies in the base field")
+    }
 
-    /// Computes the norm of an element.
-    fn norm(&self) -> Self::BaseField;
+    /// Computes the norm of an element, by default the product of its conjugates.
+    fn norm(&self) -> Self::BaseField {
+        self.conjugates()
+            .into_iter()
+            .fold(Self::one(), |product, conjugate| product * conjugate)
+            .to_base_field()
+            .expect("the norm of an element lies in the base field")
+    }
 }
 
 /// Represents a Tower of Field Extensions.
@@ -566,9 +975,15 @@ impl<T: MultiplicativeMagma + AssociativeMultiplication> MultiplicativeSemigroup
 // AdditiveMonoid
 impl<T: AdditiveSemigroup + ClosedZero> AdditiveMonoid for T {}
 
+// ScalarMultiplication
+impl<T: AdditiveMonoid> ScalarMultiplication for T {}
+
 // MultiplicativeMonoid
 impl<T: MultiplicativeSemigroup + ClosedOne> MultiplicativeMonoid for T {}
 
+// Exponentiation
+impl<T: MultiplicativeMonoid> Exponentiation for T {}
+
 // AdditiveGroup
 impl<T: AdditiveMonoid + ClosedNeg + ClosedSub + ClosedSubAssign> AdditiveGroup for T {}
 
@@ -588,9 +1003,7 @@ impl<T: AdditiveAbelianGroup + MultiplicativeMonoid + Distributive> Ring for T {
 impl<T: Ring + CommutativeMultiplication> CommutativeRing for T {}
 
 // IntegralDomain
-// Note: This is a simplified implementation. In practice, you'd need to check for zero divisors.
-
-impl<T: CommutativeRing> IntegralDomain for T {}
+impl<T: CommutativeRing + NoZeroDivisors> IntegralDomain for T {}
 
 // UniqueFactorizationDomain
 impl<T: IntegralDomain> UniqueFactorizationDomain for T {}
@@ -599,14 +1012,37 @@ impl<T: IntegralDomain> UniqueFactorizationDomain for T {}
 impl<T: UniqueFactorizationDomain> PrincipalIdealDomain for T {}
 
 // EuclideanDomain
-impl<T: PrincipalIdealDomain + Euclid> EuclideanDomain for T {}
+impl<T: PrincipalIdealDomain + Euclid + EuclideanValuation> EuclideanDomain for T {}
 
 // Field
 impl<T: EuclideanDomain + MultiplicativeAbelianGroup> Field for T {}
 
+// BatchInversion
+impl<T: Field> BatchInversion for T {}
+
 // FiniteField
 // Note: This cannot be implemented as a blanket impl because it requires specific knowledge about the field's finiteness
 
+// DynFiniteField
+impl<T: FiniteField> DynFiniteField for T {
+    type ScalarType = T::ScalarType;
+
+    fn field_characteristic(&self) -> T::ScalarType {
+        T::characteristic()
+    }
+
+    fn field_order(&self) -> T::ScalarType {
+        T::order()
+    }
+
+    fn field_extension_degree(&self) -> u32 {
+        T::extension_degree()
+    }
+}
+
+// TwoAdicField
+// Note: This cannot be implemented as a blanket impl because it requires knowledge about the roots of unity of the field
+
 // OrderedField
 impl<T: Field + PartialOrd> OrderedField for T {}
 
@@ -0,0 +1,317 @@
+//! Number-theoretic transforms over fields with large subgroups of roots of unity.
+//!
+//! The number-theoretic transform (NTT) of length n maps the coefficients a₀, ..., aₙ₋₁ of a
+//! polynomial to its values at the powers of a primitive n-th root of unity ω:
+//!
+//! âₖ = Σᵢ aᵢ ωⁱᵏ
+//!
+//! It is the discrete Fourier transform over a finite field, and turns the product of
+//! polynomials into the pointwise product of their transforms. The functions in this module
+//! transform slices in place, in natural order, and take ω from
+//! [`TwoAdicField::root_of_unity`]. Lengths that are powers of two use an iterative radix-2
+//! transform; lengths of the form 2ᵏ · bⁱ, for the small subgroup base b of the field, use a
+//! mixed-radix transform.
+//!
+//! ```
+//! use noether::{ntt, Fp};
+//!
+//! // 2¹⁶ + 1 has two-adicity 16.
+//! type F = Fp<65537>;
+//!
+//! let coefficients: Vec<F> = (1..=8).map(F::new).collect();
+//! let mut values = coefficients.clone();
+//! ntt::ntt(&mut values);
+//! ntt::intt(&mut values);
+//! assert_eq!(values, coefficients);
+//! ```
+
+use crate::polynomial::{multiple, pow};
+use crate::{Field, TwoAdicField};
+
+/// Replaces the coefficients in `values` by the values of their polynomial at the powers of a
+/// primitive n-th root of unity ω, for n the length of `values`.
+///
+/// # Panics
+/// Panics if the field has no primitive n-th root of unity.
+pub fn ntt<F: TwoAdicField>(values: &mut [F]) {
+    if values.len() > 1 {
+        transform(values, &root::<F>(values.len()));
+    }
+}
+
+/// Inverts [`ntt`], replacing the values of a polynomial at the powers of ω by its coefficients.
+///
+/// # Panics
+/// Panics if the field has no primitive n-th root of unity.
+pub fn intt<F: TwoAdicField>(values: &mut [F]) {
+    if values.len() > 1 {
+        transform(values, &root::<F>(values.len()).inv());
+        let n_inv = multiple(&F::one(), values.len()).inv();
+        for value in values.iter_mut() {
+            *value *= n_inv.clone();
+        }
+    }
+}
+
+/// Replaces the coefficients in `values` by the values of their polynomial on the coset g·⟨ω⟩,
+/// at the points g·ωᵏ, for the non-zero `shift` g.
+///
+/// Evaluating on a coset avoids the roots of unity themselves, where vanishing polynomials such
+/// as xⁿ - 1 are zero.
+///
+/// # Panics
+/// Panics if the field has no primitive n-th root of unity.
+pub fn coset_ntt<F: TwoAdicField>(values: &mut [F], shift: &F) {
+    scale_by_powers(values, shift);
+    ntt(values);
+}
+
+/// Inverts [`coset_ntt`] with the same `shift`.
+///
+/// # Panics
+/// Panics if the field has no primitive n-th root of unity, or if `shift` is zero.
+pub fn coset_intt<F: TwoAdicField>(values: &mut [F], shift: &F) {
+    intt(values);
+    scale_by_powers(values, &shift.clone().inv());
+}
+
+/// Returns the smallest length of at least `n` which the field supports, or `None` if there is
+/// none.
+pub fn domain_size<F: TwoAdicField>(n: usize) -> Option<usize> {
+    let n = n.max(1) as u64;
+    let (base, adicity) = F::SMALL_SUBGROUP_BASE.unwrap_or((1, 0));
+    let mut best: Option<u64> = None;
+    let mut odd = 1u64;
+    for _ in 0..=adicity {
+        // The smallest power of two reaching n when multiplied by the odd part.
+        let k = n.div_ceil(odd).next_power_of_two().trailing_zeros();
+        if k <= F::TWO_ADICITY {
+            let size = odd << k;
+            best = Some(best.map_or(size, |best| best.min(size)));
+        }
+        match odd.checked_mul(base) {
+            Some(next) if next < 2 * n => odd = next,
+            _ => break,
+        }
+    }
+    best.and_then(|size| usize::try_from(size).ok())
+}
+
+/// Returns a primitive n-th root of unity, panicking if the field has none.
+fn root<F: TwoAdicField>(n: usize) -> F {
+    F::root_of_unity(n as u64)
+        .unwrap_or_else(|| panic!("the field has no primitive root of unity of order {n}"))
+}
+
+/// Multiplies `values[i]` by `factor`ⁱ.
+fn scale_by_powers<F: Field>(values: &mut [F], factor: &F) {
+    let mut power = F::one();
+    for value in values.iter_mut() {
+        *value *= power.clone();
+        power *= factor.clone();
+    }
+}
+
+/// Transforms `values` with the primitive root of unity `root` of order `values.len()`.
+fn transform<F: Field>(values: &mut [F], root: &F) {
+    if values.len().is_power_of_two() {
+        radix2(values, root);
+    } else {
+        let result = mixed_radix(values, root);
+        values.clone_from_slice(&result);
+    }
+}
+
+/// The iterative Cooley-Tukey transform for power-of-two lengths.
+fn radix2<F: Field>(values: &mut [F], root: &F) {
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    // twiddles[i] = rootⁱ; a block of size m uses the powers of root^(n/m).
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut power = F::one();
+    for _ in 0..n / 2 {
+        twiddles.push(power.clone());
+        power *= root.clone();
+    }
+    let mut half = 1;
+    while half < n {
+        let stride = n / (2 * half);
+        for block in values.chunks_mut(2 * half) {
+            let (low, high) = block.split_at_mut(half);
+            for (j, (a, b)) in low.iter_mut().zip(high).enumerate() {
+                let t = twiddles[j * stride].clone() * b.clone();
+                *b = a.clone() - t.clone();
+                *a += t;
+            }
+        }
+        half *= 2;
+    }
+}
+
+/// The recursive Cooley-Tukey transform, splitting off the smallest prime factor r of the
+/// length n = r·m at each step:
+///
+/// âₖ₊ₘₗ = Σₜ ω^(t(k + ml)) · Âₜ,ₖ
+///
+/// where Âₜ is the transform of length m of the subsequence aₜ, aₜ₊ᵣ, aₜ₊₂ᵣ, ...
+fn mixed_radix<F: Field>(values: &[F], root: &F) -> Vec<F> {
+    let n = values.len();
+    if n == 1 {
+        return values.to_vec();
+    }
+    let r = (2..=n).find(|&r| n % r == 0).unwrap();
+    let m = n / r;
+    let sub_root = pow(root, r);
+    let parts: Vec<Vec<F>> = (0..r)
+        .map(|t| {
+            let part: Vec<F> = values[t..].iter().step_by(r).cloned().collect();
+            mixed_radix(&part, &sub_root)
+        })
+        .collect();
+
+    let mut powers = Vec::with_capacity(n);
+    let mut power = F::one();
+    for _ in 0..n {
+        powers.push(power.clone());
+        power *= root.clone();
+    }
+    let mut result = vec![F::zero(); n];
+    for (index, value) in result.iter_mut().enumerate() {
+        let k = index % m;
+        for (t, part) in parts.iter().enumerate() {
+            *value += powers[t * index % n].clone() * part[k].clone();
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DensePoly, Fp};
+    use num_traits::Zero;
+
+    /// p - 1 = 2¹⁵ · 3⁴, so the field supports lengths 2ᵏ · 3ʲ up to 2¹⁵ · 3⁴.
+    type F = Fp<2654209>;
+
+    /// Returns `n` pseudo-random field elements.
+    fn elements(n: usize, seed: u64) -> Vec<F> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                F::new(state)
+            })
+            .collect()
+    }
+
+    /// The transform by its definition âₖ = Σᵢ aᵢ ωⁱᵏ.
+    fn dft(values: &[F], root: &F) -> Vec<F> {
+        let n = values.len();
+        (0..n)
+            .map(|k| {
+                let point = pow(root, k);
+                DensePoly::new(values.to_vec()).evaluate(&point)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn the_field_has_the_advertised_subgroups() {
+        assert_eq!(F::TWO_ADICITY, 15);
+        assert_eq!(F::SMALL_SUBGROUP_BASE, Some((3, 4)));
+        assert_eq!(domain_size::<F>(5), Some(6));
+        assert_eq!(domain_size::<F>(129), Some(144));
+        assert_eq!(domain_size::<F>(1 << 15), Some(1 << 15));
+        assert_eq!(domain_size::<F>((1 << 15) * 81 + 1), None);
+    }
+
+    #[test]
+    fn transforms_match_the_definition() {
+        for n in [2, 8, 3, 6, 9, 12, 18, 81, 96] {
+            let coefficients = elements(n, n as u64);
+            let mut values = coefficients.clone();
+            ntt(&mut values);
+            assert_eq!(values, dft(&coefficients, &root::<F>(n)), "length {n}");
+        }
+    }
+
+    #[test]
+    fn radix2_transforms_round_trip() {
+        for n in [1, 2, 4, 64, 1024, 1 << 15] {
+            let coefficients = elements(n, 1);
+            let mut values = coefficients.clone();
+            ntt(&mut values);
+            intt(&mut values);
+            assert_eq!(values, coefficients, "length {n}");
+        }
+    }
+
+    #[test]
+    fn mixed_radix_transforms_round_trip() {
+        for n in [3, 6, 9, 24, 81, 144, 162, 81 * 64] {
+            let coefficients = elements(n, 2);
+            let mut values = coefficients.clone();
+            ntt(&mut values);
+            intt(&mut values);
+            assert_eq!(values, coefficients, "length {n}");
+        }
+    }
+
+    #[test]
+    fn coset_transforms_evaluate_on_the_coset_and_round_trip() {
+        let shift = F::new(5);
+        for n in [16, 48, 54] {
+            let coefficients = elements(n, 3);
+            let mut values = coefficients.clone();
+            coset_ntt(&mut values, &shift);
+            let polynomial = DensePoly::new(coefficients.clone());
+            let omega = root::<F>(n);
+            for (k, value) in values.iter().enumerate() {
+                let point = shift * pow(&omega, k);
+                assert_eq!(*value, polynomial.evaluate(&point), "length {n}, point {k}");
+            }
+            coset_intt(&mut values, &shift);
+            assert_eq!(values, coefficients, "length {n}");
+        }
+    }
+
+    #[test]
+    fn fast_multiplication_matches_the_definition() {
+        // (m, n) with products of power-of-two length, of mixed length and below the threshold.
+        for (m, n) in [
+            (64, 64),
+            (65, 65),
+            (70, 90),
+            (200, 300),
+            (130, 100),
+            (10, 500),
+        ] {
+            let a = elements(m, m as u64);
+            let b = elements(n, n as u64 + 1);
+            let mut expected = vec![F::zero(); m + n - 1];
+            for (i, x) in a.iter().enumerate() {
+                for (j, y) in b.iter().enumerate() {
+                    expected[i + j] += *x * *y;
+                }
+            }
+            let product = DensePoly::new(a).fast_mul(&DensePoly::new(b));
+            assert_eq!(product.coefficients(), &expected[..], "{m} × {n}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the field has no primitive root of unity of order 5")]
+    fn lengths_outside_the_subgroups_panic() {
+        ntt(&mut elements(5, 4));
+    }
+}
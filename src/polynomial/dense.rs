@@ -0,0 +1,563 @@
+//! Univariate polynomials stored as a vector of coefficients.
+
+use super::{multiplication, write_terms};
+use crate::ntt;
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    Distributive, EuclideanValuation, ExactDivision, Field, NoZeroDivisors, Polynomial, Ring,
+    TwoAdicField, VectorSpace,
+};
+use num_traits::{Euclid, One, Zero};
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Represents a univariate polynomial over a ring R, storing every coefficient up to the degree.
+///
+/// # Mathematical Definition
+/// The polynomial ring R\[x\] consists of the finite sums a₀ + a₁x + ... + aₙxⁿ with aᵢ ∈ R, added
+/// coefficient-wise and multiplied by the convolution
+/// (ab)ₖ = Σᵢ₊ⱼ₌ₖ aᵢbⱼ
+///
+/// # Properties
+/// - R\[x\] is a commutative ring whenever R is, and an integral domain whenever R is
+/// - Over a field F, F\[x\] is a Euclidean domain with the degree as its Euclidean function: for
+///   b ≠ 0 there are unique q, r with a = qb + r and r = 0 or deg(r) < deg(b)
+///
+/// The coefficients are kept lowest degree first, without trailing zeros, so that equal
+/// polynomials have equal representations. The zero polynomial has no coefficients, and reports
+/// degree 0 through [`Polynomial::degree`].
+///
+/// `*` multiplies by the definition or with Karatsuba's algorithm, depending on the size, over
+/// any ring. The faster algorithms need more of the coefficients, and since stable Rust cannot
+/// specialize `*` for them, they are separate methods: [`DensePoly::mul_toom3`] for rings which
+/// divide exactly by 2 and 3, and [`DensePoly::fast_mul`], with the number-theoretic transform,
+/// for fields with large subgroups of roots of unity.
+///
+/// # Example
+/// ```
+/// use noether::{DensePoly, Fp, Polynomial};
+///
+/// type F7 = Fp<7>;
+///
+/// // x² + 3x + 2 = (x + 1)(x + 2)
+/// let p = DensePoly::new(vec![F7::new(2), F7::new(3), F7::new(1)]);
+/// let q = DensePoly::new(vec![F7::new(1), F7::new(1)]);
+///
+/// assert_eq!(p.evaluate(&F7::new(6)), F7::new(0));
+/// assert_eq!(p.derivative(), DensePoly::new(vec![F7::new(3), F7::new(2)]));
+/// assert_eq!(p.clone() / q.clone(), DensePoly::new(vec![F7::new(2), F7::new(1)]));
+/// assert_eq!((p % q).degree(), 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DensePoly<R> {
+    coefficients: Vec<R>,
+}
+
+impl<R: Ring> DensePoly<R> {
+    /// Creates the polynomial with the given coefficients, lowest degree first.
+    ///
+    /// Trailing zero coefficients are dropped.
+    pub fn new(coefficients: Vec<R>) -> Self {
+        let mut polynomial = DensePoly { coefficients };
+        polynomial.normalize();
+        polynomial
+    }
+
+    /// Creates the monomial `coefficient · xᵈᵉᵍʳᵉᵉ`.
+    pub fn monomial(coefficient: R, degree: usize) -> Self {
+        let mut coefficients = vec![R::zero(); degree];
+        coefficients.push(coefficient);
+        Self::new(coefficients)
+    }
+
+    /// Creates the indeterminate x.
+    pub fn x() -> Self {
+        Self::monomial(R::one(), 1)
+    }
+
+    /// Returns the coefficients, lowest degree first, without trailing zeros.
+    pub fn coefficients(&self) -> &[R] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficient of the highest power of x, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&R> {
+        self.coefficients.last()
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    pub fn evaluate(&self, x: &R) -> R {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(R::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// Returns the formal derivative a₁ + 2a₂x + ... + naₙxⁿ⁻¹.
+    ///
+    /// The integer multiples are sums of ones in R, so in characteristic p the derivative of xᵖ
+    /// is zero.
+    pub fn derivative(&self) -> Self {
+        let mut multiple = R::zero();
+        let coefficients = self
+            .coefficients
+            .iter()
+            .skip(1)
+            .map(|c| {
+                multiple = multiple.clone() + R::one();
+                multiple.clone() * c.clone()
+            })
+            .collect();
+        Self::new(coefficients)
+    }
+
+    /// Returns the polynomial with every coefficient multiplied by `scalar`.
+    pub fn scale_by(&self, scalar: &R) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .map(|c| c.clone() * scalar.clone())
+                .collect(),
+        )
+    }
+
+    /// Drops the trailing zero coefficients.
+    fn normalize(&mut self) {
+        while self.coefficients.last().is_some_and(R::is_zero) {
+            self.coefficients.pop();
+        }
+    }
+}
+
+impl<F: Field> DensePoly<F> {
+    /// Returns the quotient and remainder of the division of `self` by `divisor`.
+    ///
+    /// The remainder is zero or has a smaller degree than the divisor.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let Some(leading) = divisor.leading_coefficient() else {
+            panic!("attempt to divide by zero");
+        };
+        let n = divisor.coefficients.len();
+        if self.coefficients.len() < n {
+            return (Self::zero(), self.clone());
+        }
+
+        // Schoolbook long division, cancelling the highest remaining term at each step.
+        let leading_inv = leading.clone().inv();
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![F::zero(); remainder.len() - n + 1];
+        for i in (0..quotient.len()).rev() {
+            let factor = remainder[i + n - 1].clone() * leading_inv.clone();
+            if factor.is_zero() {
+                continue;
+            }
+            for (j, d) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] -= factor.clone() * d.clone();
+            }
+            quotient[i] = factor;
+        }
+        remainder.truncate(n - 1);
+        (Self::new(quotient), Self::new(remainder))
+    }
+
+    /// Returns the polynomial divided by its leading coefficient, or zero for zero.
+    pub fn monic(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(leading) => self.scale_by(&leading.clone().inv()),
+            None => Self::zero(),
+        }
+    }
+}
+
+impl<R: ExactDivision> DensePoly<R> {
+    /// Returns `self * rhs`, computed with the Toom-Cook algorithm of three parts in O(n^1.46)
+    /// once both operands have at least 96 coefficients, if R divides exactly by 2 and 3.
+    ///
+    /// Toom-3 applies over the integers and over rings in which 2 and 3 are units, such as
+    /// fields of characteristic other than 2 and 3; see [`ExactDivision`]. Other rings, and
+    /// smaller operands, are multiplied like `*`.
+    ///
+    /// # Example
+    /// ```
+    /// use noether::{DensePoly, Fp};
+    ///
+    /// type F = Fp<65537>;
+    ///
+    /// let p = DensePoly::new((0..200).map(F::new).collect());
+    /// let q = DensePoly::new((0..300).map(|i| F::new(i * i)).collect());
+    /// assert_eq!(p.mul_toom3(&q), p * q);
+    /// ```
+    pub fn mul_toom3(&self, rhs: &Self) -> Self {
+        Self::new(multiplication::multiply(
+            &self.coefficients,
+            &rhs.coefficients,
+        ))
+    }
+}
+
+/// The length of the shorter operand from which [`DensePoly::fast_mul`] uses the NTT.
+const NTT_THRESHOLD: usize = 64;
+
+impl<F: TwoAdicField> DensePoly<F> {
+    /// Returns `self * rhs`, computed with the number-theoretic transform in O(n log n) when
+    /// both operands have at least 64 coefficients.
+    ///
+    /// Smaller products, and products longer than the largest transform the field supports, use
+    /// the `*` operator. `*` itself does not use the transform: it is implemented for every ring,
+    /// and stable Rust has no specialization through which it could pick this method for
+    /// [`TwoAdicField`]s.
+    ///
+    /// # Example
+    /// ```
+    /// use noether::{DensePoly, Fp};
+    ///
+    /// type F = Fp<65537>;
+    ///
+    /// let p = DensePoly::new((0..200).map(F::new).collect());
+    /// let q = DensePoly::new((0..300).map(|i| F::new(i * i)).collect());
+    /// assert_eq!(p.fast_mul(&q), p * q);
+    /// ```
+    pub fn fast_mul(&self, rhs: &Self) -> Self {
+        let (n, m) = (self.coefficients.len(), rhs.coefficients.len());
+        if n.min(m) < NTT_THRESHOLD {
+            return self.clone() * rhs.clone();
+        }
+        let Some(size) = ntt::domain_size::<F>(n + m - 1) else {
+            return self.clone() * rhs.clone();
+        };
+        let mut a = self.coefficients.clone();
+        let mut b = rhs.coefficients.clone();
+        a.resize(size, F::zero());
+        b.resize(size, F::zero());
+        ntt::ntt(&mut a);
+        ntt::ntt(&mut b);
+        for (x, y) in a.iter_mut().zip(b) {
+            *x *= y;
+        }
+        ntt::intt(&mut a);
+        a.truncate(n + m - 1);
+        Self::new(a)
+    }
+}
+
+impl<R: Ring> From<R> for DensePoly<R> {
+    /// Embeds `value` as a constant polynomial.
+    fn from(value: R) -> Self {
+        Self::new(vec![value])
+    }
+}
+
+impl<R: Ring + fmt::Display> fmt::Display for DensePoly<R> {
+    /// Formats the polynomial from the highest degree down, as in `2x^2 + x + 5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, c)| !c.is_zero());
+        write_terms(f, terms)
+    }
+}
+
+impl<R: Ring> Add for DensePoly<R> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (mut long, short) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self.coefficients, rhs.coefficients)
+        } else {
+            (rhs.coefficients, self.coefficients)
+        };
+        for (a, b) in long.iter_mut().zip(short) {
+            *a += b;
+        }
+        Self::new(long)
+    }
+}
+
+impl<R: Ring> AddAssign for DensePoly<R> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = std::mem::replace(self, Self::zero()) + rhs;
+    }
+}
+
+impl<R: Ring> Neg for DensePoly<R> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DensePoly {
+            coefficients: self.coefficients.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl<R: Ring> Sub for DensePoly<R> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<R: Ring> SubAssign for DensePoly<R> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self += -rhs;
+    }
+}
+
+impl<R: Ring> Mul for DensePoly<R> {
+    type Output = Self;
+
+    /// Multiplies by the definition for short operands, and with Karatsuba's algorithm in
+    /// O(n^1.58) once both have at least 32 coefficients.
+    ///
+    /// Over a [bounded](crate::AssociativeAddition::BOUNDED) ring such as
+    /// [`Checked`](crate::Checked), whose sums panic on overflow, it always multiplies by the
+    /// definition, so that it only panics if the sums of the definition overflow.
+    fn mul(self, rhs: Self) -> Self {
+        // Over a ring with zero divisors the leading coefficients can cancel.
+        Self::new(multiplication::karatsuba(
+            &self.coefficients,
+            &rhs.coefficients,
+        ))
+    }
+}
+
+impl<R: Ring> MulAssign for DensePoly<R> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::replace(self, Self::zero()) * rhs;
+    }
+}
+
+impl<R: Ring> Zero for DensePoly<R> {
+    fn zero() -> Self {
+        DensePoly {
+            coefficients: Vec::new(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<R: Ring> One for DensePoly<R> {
+    fn one() -> Self {
+        Self::new(vec![R::one()])
+    }
+}
+
+impl<F: Field> Div for DensePoly<F> {
+    type Output = Self;
+
+    /// Returns the quotient of polynomial long division.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<F: Field> DivAssign for DensePoly<F> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).0;
+    }
+}
+
+impl<F: Field> Rem for DensePoly<F> {
+    type Output = Self;
+
+    /// Returns the remainder of polynomial long division.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<F: Field> RemAssign for DensePoly<F> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).1;
+    }
+}
+
+impl<F: Field> Euclid for DensePoly<F> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).0
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).1
+    }
+}
+
+impl<F: Field> EuclideanValuation for DensePoly<F> {
+    /// Returns the degree, which Euclidean division lowers.
+    fn euclidean_size(&self) -> u128 {
+        self.degree() as u128
+    }
+}
+
+impl<F: Field> Polynomial for DensePoly<F> {
+    type Coefficient = F;
+
+    fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    fn coefficient(&self, degree: usize) -> F {
+        self.coefficients
+            .get(degree)
+            .cloned()
+            .unwrap_or_else(F::zero)
+    }
+}
+
+impl<F: Field> VectorSpace for DensePoly<F> {
+    type Scalar = F;
+
+    fn scale(&self, scalar: &F) -> Self {
+        self.scale_by(scalar)
+    }
+
+    /// Returns `None`, since F\[x\] has the infinite basis 1, x, x², ...
+    fn dimension(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<R: CommutativeAddition> CommutativeAddition for DensePoly<R> {}
+impl<R: AssociativeAddition> AssociativeAddition for DensePoly<R> {
+    const BOUNDED: bool = R::BOUNDED;
+}
+impl<R: CommutativeMultiplication> CommutativeMultiplication for DensePoly<R> {}
+impl<R: AssociativeMultiplication> AssociativeMultiplication for DensePoly<R> {}
+impl<R: Distributive> Distributive for DensePoly<R> {}
+impl<R: NoZeroDivisors> NoZeroDivisors for DensePoly<R> {}
+
+/// Divides every coefficient, so that Toom-3 also applies to polynomials over polynomials.
+impl<R: ExactDivision> ExactDivision for DensePoly<R> {
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        let coefficients = self.coefficients.iter().map(|c| c.div_exact(n));
+        coefficients.collect::<Option<_>>().map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_euclidean_domain;
+    use crate::{Checked, Fp};
+
+    type F = Fp<101>;
+
+    /// Returns a generator of polynomials of degree below `max_len` over 𝔽₁₀₁.
+    fn polynomials(max_len: u64, seed: u64) -> impl FnMut() -> DensePoly<F> {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        move || {
+            let len = next() % max_len;
+            DensePoly::new((0..len).map(|_| F::new(next())).collect())
+        }
+    }
+
+    #[test]
+    fn division_leaves_a_remainder_of_smaller_degree() {
+        let mut a = polynomials(40, 1);
+        let mut b = polynomials(12, 2);
+        for _ in 0..200 {
+            let (a, b) = (a(), b());
+            if b.is_zero() {
+                continue;
+            }
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(q.clone() * b.clone() + r.clone(), a, "{a} / {b}");
+            assert!(r.is_zero() || r.degree() < b.degree(), "{a} % {b} = {r}");
+            assert_eq!(a.div_euclid(&b), q);
+            assert_eq!(a.rem_euclid(&b), r);
+        }
+    }
+
+    #[test]
+    fn dividing_by_a_constant_or_a_larger_divisor() {
+        let a = DensePoly::new(vec![F::new(4), F::new(6), F::new(8)]);
+        let (q, r) = a.div_rem(&DensePoly::from(F::new(2)));
+        assert_eq!(q, DensePoly::new(vec![F::new(2), F::new(3), F::new(4)]));
+        assert!(r.is_zero());
+
+        let b = DensePoly::monomial(F::one(), 5);
+        assert_eq!(a.div_rem(&b), (DensePoly::zero(), a.clone()));
+        assert_eq!(a.monic().leading_coefficient(), Some(&F::one()));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn dividing_by_zero_panics() {
+        let _ = DensePoly::x() / DensePoly::<F>::zero();
+    }
+
+    #[test]
+    fn polynomials_over_a_field_form_a_euclidean_domain() {
+        check_euclidean_domain(polynomials(8, 3), 300).unwrap();
+    }
+
+    #[test]
+    fn derivatives_follow_the_product_rule() {
+        let mut gen = polynomials(20, 4);
+        for _ in 0..50 {
+            let (a, b) = (gen(), gen());
+            let lhs = (a.clone() * b.clone()).derivative();
+            let rhs = a.derivative() * b.clone() + a * b.derivative();
+            assert_eq!(lhs, rhs);
+        }
+        // d/dx x⁷ = 7x⁶ = 0 in characteristic 7.
+        assert!(DensePoly::monomial(Fp::<7>::one(), 7)
+            .derivative()
+            .is_zero());
+    }
+
+    #[test]
+    fn evaluation_at_the_roots_of_a_product() {
+        // (x - 3)(x - 5)(x + 1)
+        let factors = [3, 5, 100].map(|r| DensePoly::new(vec![-F::new(r), F::one()]));
+        let product = factors.into_iter().fold(DensePoly::one(), |p, f| p * f);
+        for r in [3, 5, 100] {
+            assert!(product.evaluate(&F::new(r)).is_zero());
+        }
+        assert_eq!(product.evaluate(&F::zero()), F::new(15));
+    }
+
+    #[test]
+    fn products_over_bounded_integers_only_overflow_with_the_definition() {
+        // Karatsuba would add the halves of a to 2⁶³, while every partial sum of the definition
+        // of a · (1 - x + x⁴⁰) lies within ±2⁶².
+        let a = vec![1i64 << 62; 64];
+        let mut b = vec![0i64; 41];
+        (b[0], b[1], b[40]) = (1, -1, 1);
+        let mut expected = vec![0i64; 104];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                expected[i + j] += x * y;
+            }
+        }
+
+        let checked = |c: &[i64]| DensePoly::new(c.iter().map(|&c| Checked(c)).collect());
+        let (a, b) = (checked(&a), checked(&b));
+        let product = a.clone() * b.clone();
+        assert_eq!(product, checked(&expected));
+        assert_eq!(a.mul_toom3(&b), product);
+    }
+}
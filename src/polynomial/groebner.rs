@@ -0,0 +1,299 @@
+//! Gröbner bases of ideals of multivariate polynomials over a field.
+
+use super::{MPoly, Monomial, MonomialOrder};
+use crate::Field;
+use num_traits::Zero;
+use std::collections::HashSet;
+use std::fmt;
+
+/// The reduced Gröbner basis of an ideal of F[x₀, ..., xₙ] with respect to the order `O`.
+///
+/// # Mathematical Definition
+/// A Gröbner basis of an ideal I is a finite set G ⊂ I whose leading monomials generate the
+/// ideal of leading monomials of I. Equivalently, dividing any f by G leaves a remainder that
+/// does not depend on the order of the divisors, and which is zero exactly when f ∈ I.
+///
+/// # Properties
+/// - The basis is reduced: its elements are monic, and no term of an element is divisible by the
+///   leading monomial of another
+/// - The reduced Gröbner basis of an ideal is unique for a given monomial order, so two ideals
+///   are equal exactly when their bases are
+/// - The ideal is the whole ring exactly when the basis is {1}
+///
+/// The basis is computed with Buchberger's algorithm, selecting the pair with the smallest lcm
+/// of leading monomials first, and skipping pairs by Buchberger's product and chain criteria.
+///
+/// # Example
+/// ```
+/// use noether::{Fp, GroebnerBasis, Lex, MPoly};
+/// use num_traits::{Inv, One};
+///
+/// type F = Fp<101>;
+/// type P = MPoly<F, Lex>;
+///
+/// let x = P::variable(0);
+/// let y = P::variable(1);
+///
+/// // The intersection of the circle x² + y² = 1 and the line x = y.
+/// let circle = x.clone() * x.clone() + y.clone() * y.clone() - P::one();
+/// let line = x.clone() - y.clone();
+/// let ideal = GroebnerBasis::new(&[circle.clone(), line]);
+///
+/// // With x > y, the basis is {y² - 1/2, x - y}: elimination leaves a polynomial in y alone.
+/// let half = F::new(2).inv();
+/// assert_eq!(ideal.basis(), [y.clone() * y.clone() - P::from(half), x.clone() - y.clone()]);
+/// assert!(ideal.contains(&(x.clone() * x.clone() - y.clone() * y.clone())));
+/// assert!(!ideal.contains(&x));
+///
+/// // xy ≡ y² ≡ 1/2 modulo the ideal.
+/// assert_eq!(ideal.reduce(&(x * y)), P::from(half));
+/// ```
+pub struct GroebnerBasis<F, O> {
+    basis: Vec<MPoly<F, O>>,
+}
+
+impl<F: Field, O: MonomialOrder> GroebnerBasis<F, O> {
+    /// Computes the reduced Gröbner basis of the ideal generated by `generators`.
+    ///
+    /// Zero generators are ignored, and the zero ideal has an empty basis.
+    pub fn new(generators: &[MPoly<F, O>]) -> Self {
+        let mut basis: Vec<MPoly<F, O>> = generators
+            .iter()
+            .filter(|g| !g.is_zero())
+            .map(MPoly::monic)
+            .collect();
+        let mut pairs: HashSet<(usize, usize)> = (0..basis.len())
+            .flat_map(|j| (0..j).map(move |i| (i, j)))
+            .collect();
+
+        while let Some(&(i, j)) = pairs
+            .iter()
+            .min_by(|&&p, &&q| O::compare(&pair_lcm(&basis, p), &pair_lcm(&basis, q)))
+        {
+            pairs.remove(&(i, j));
+            if can_skip(&basis, &pairs, i, j) {
+                continue;
+            }
+            let remainder = basis[i].s_polynomial(&basis[j]).reduce(&basis);
+            if !remainder.is_zero() {
+                let k = basis.len();
+                basis.push(remainder.monic());
+                pairs.extend((0..k).map(|i| (i, k)));
+            }
+        }
+
+        // Drop the elements whose leading monomial is a multiple of another's, keeping one of
+        // each set of equal leading monomials, then reduce the rest against each other.
+        let mut minimal: Vec<MPoly<F, O>> = Vec::new();
+        for (i, g) in basis.iter().enumerate() {
+            let lead = g.leading_monomial().unwrap();
+            let redundant = basis.iter().enumerate().any(|(j, h)| {
+                let other = h.leading_monomial().unwrap();
+                other.divides(lead) && (other != lead || j < i)
+            });
+            if !redundant {
+                minimal.push(g.clone());
+            }
+        }
+        for i in 0..minimal.len() {
+            let others: Vec<_> = minimal
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, g)| g.clone())
+                .collect();
+            minimal[i] = minimal[i].reduce(&others);
+        }
+        minimal.sort_by(|f, g| {
+            O::compare(f.leading_monomial().unwrap(), g.leading_monomial().unwrap())
+        });
+        GroebnerBasis { basis: minimal }
+    }
+
+    /// Returns the elements of the basis, in increasing order of their leading monomials.
+    pub fn basis(&self) -> &[MPoly<F, O>] {
+        &self.basis
+    }
+
+    /// Returns the normal form of `f` modulo the ideal: the unique remainder of the division of
+    /// `f` by the basis.
+    ///
+    /// Two polynomials are congruent modulo the ideal exactly when their normal forms are equal.
+    pub fn reduce(&self, f: &MPoly<F, O>) -> MPoly<F, O> {
+        f.reduce(&self.basis)
+    }
+
+    /// Returns whether `f` belongs to the ideal.
+    pub fn contains(&self, f: &MPoly<F, O>) -> bool {
+        self.reduce(f).is_zero()
+    }
+}
+
+/// Returns the lcm of the leading monomials of the pair `(i, j)` of basis elements.
+fn pair_lcm<F: Field, O: MonomialOrder>(basis: &[MPoly<F, O>], (i, j): (usize, usize)) -> Monomial {
+    let a = basis[i].leading_monomial().unwrap();
+    a.lcm(basis[j].leading_monomial().unwrap())
+}
+
+/// Returns whether the S-polynomial of the pair `(i, j)` is known to reduce to zero.
+///
+/// By the product criterion, this is the case when the leading monomials are coprime. By the
+/// chain criterion, it is the case when the leading monomial of a third element k divides their
+/// lcm, and the pairs `(i, k)` and `(j, k)` have already been processed.
+fn can_skip<F: Field, O: MonomialOrder>(
+    basis: &[MPoly<F, O>],
+    pending: &HashSet<(usize, usize)>,
+    i: usize,
+    j: usize,
+) -> bool {
+    let a = basis[i].leading_monomial().unwrap();
+    let b = basis[j].leading_monomial().unwrap();
+    if a.is_coprime(b) {
+        return true;
+    }
+    let lcm = a.lcm(b);
+    let is_pending = |p: usize, q: usize| pending.contains(&(p.min(q), p.max(q)));
+    (0..basis.len()).any(|k| {
+        k != i
+            && k != j
+            && basis[k].leading_monomial().unwrap().divides(&lcm)
+            && !is_pending(i, k)
+            && !is_pending(j, k)
+    })
+}
+
+// Derived impls would require the order type to implement the traits as well.
+
+impl<F: Clone, O> Clone for GroebnerBasis<F, O> {
+    fn clone(&self) -> Self {
+        GroebnerBasis {
+            basis: self.basis.clone(),
+        }
+    }
+}
+
+impl<F: PartialEq, O> PartialEq for GroebnerBasis<F, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.basis == other.basis
+    }
+}
+
+impl<F: Eq, O> Eq for GroebnerBasis<F, O> {}
+
+impl<F: fmt::Debug, O> fmt::Debug for GroebnerBasis<F, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.basis).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::polynomial::multivariate::{GrLex, Lex};
+    use crate::Fp;
+
+    type F = Fp<101>;
+
+    fn c(value: i64) -> F {
+        F::new(value.rem_euclid(101) as u64)
+    }
+
+    /// Returns the polynomial with the given `(coefficient, exponents)` terms.
+    fn poly<O: MonomialOrder>(terms: &[(i64, [usize; 3])]) -> MPoly<F, O> {
+        MPoly::new(
+            terms
+                .iter()
+                .map(|&(a, e)| (Monomial::new(e.to_vec()), c(a))),
+        )
+    }
+
+    // The examples of Cox, Little and O'Shea, Ideals, Varieties, and Algorithms, §2.7 and §2.8.
+    // Over 𝔽₁₀₁, 1/2 = 51 and 1/4 = 76.
+
+    #[test]
+    fn reduced_basis_in_grlex() {
+        let f1 = poly::<GrLex>(&[(1, [3, 0, 0]), (-2, [1, 1, 0])]);
+        let f2 = poly::<GrLex>(&[(1, [2, 1, 0]), (-2, [0, 2, 0]), (1, [1, 0, 0])]);
+        let groebner = GroebnerBasis::new(&[f1, f2]);
+        let expected = [
+            poly(&[(1, [0, 2, 0]), (-51, [1, 0, 0])]),
+            poly(&[(1, [1, 1, 0])]),
+            poly(&[(1, [2, 0, 0])]),
+        ];
+        assert_eq!(groebner.basis(), expected);
+    }
+
+    #[test]
+    fn reduced_basis_in_lex_eliminates_variables() {
+        let f1 = poly::<Lex>(&[
+            (1, [2, 0, 0]),
+            (1, [0, 2, 0]),
+            (1, [0, 0, 2]),
+            (-1, [0, 0, 0]),
+        ]);
+        let f2 = poly::<Lex>(&[(1, [2, 0, 0]), (1, [0, 0, 2]), (-1, [0, 1, 0])]);
+        let f3 = poly::<Lex>(&[(1, [1, 0, 0]), (-1, [0, 0, 1])]);
+        let groebner = GroebnerBasis::new(&[f1, f2, f3]);
+        let expected = [
+            poly(&[(1, [0, 0, 4]), (51, [0, 0, 2]), (-76, [0, 0, 0])]),
+            poly(&[(1, [0, 1, 0]), (-2, [0, 0, 2])]),
+            poly(&[(1, [1, 0, 0]), (-1, [0, 0, 1])]),
+        ];
+        assert_eq!(groebner.basis(), expected);
+    }
+
+    #[test]
+    fn membership() {
+        let f1 = poly::<GrLex>(&[(1, [1, 0, 1]), (-1, [0, 2, 0])]);
+        let f2 = poly::<GrLex>(&[(1, [3, 0, 0]), (-1, [0, 0, 2])]);
+        let groebner = GroebnerBasis::new(&[f1.clone(), f2.clone()]);
+        let expected = [
+            f1.clone(),
+            f2.clone(),
+            poly(&[(1, [2, 2, 0]), (-1, [0, 0, 3])]),
+            poly(&[(1, [1, 4, 0]), (-1, [0, 0, 4])]),
+            poly(&[(1, [0, 6, 0]), (-1, [0, 0, 5])]),
+        ];
+        assert_eq!(groebner.basis(), expected);
+
+        let f = poly(&[(-4, [2, 2, 2]), (1, [0, 6, 0]), (3, [0, 0, 5])]);
+        assert!(groebner.contains(&f));
+        let g = poly(&[(1, [1, 1, 0]), (-5, [0, 0, 2]), (1, [1, 0, 0])]);
+        assert!(!groebner.contains(&g));
+        assert_eq!(groebner.reduce(&g), g);
+
+        let a = poly(&[(3, [0, 1, 1]), (-7, [2, 0, 0]), (1, [0, 0, 0])]);
+        let b = poly(&[(5, [1, 2, 3]), (2, [0, 0, 1])]);
+        let combination = a * f1 + b * f2;
+        assert!(groebner.contains(&combination));
+        assert!(!groebner.contains(&(combination + poly(&[(1, [1, 0, 0])]))));
+        assert!(!groebner.contains(&poly(&[(1, [0, 0, 0])])));
+    }
+
+    #[test]
+    fn unit_ideal_and_zero_ideal() {
+        let x = poly::<Lex>(&[(1, [1, 0, 0])]);
+        let x_minus_one = poly::<Lex>(&[(1, [1, 0, 0]), (-1, [0, 0, 0])]);
+        let groebner = GroebnerBasis::new(&[x, x_minus_one]);
+        assert_eq!(groebner.basis(), [poly(&[(1, [0, 0, 0])])]);
+        assert!(groebner.contains(&poly(&[(17, [2, 5, 1])])));
+
+        let zero = GroebnerBasis::<F, Lex>::new(&[MPoly::zero()]);
+        assert!(zero.basis().is_empty());
+        assert!(zero.contains(&MPoly::zero()));
+        assert!(!zero.contains(&poly(&[(1, [0, 0, 0])])));
+    }
+
+    #[test]
+    fn basis_depends_only_on_the_ideal() {
+        let f1 = poly::<GrLex>(&[(1, [3, 0, 0]), (-2, [1, 1, 0])]);
+        let f2 = poly::<GrLex>(&[(1, [2, 1, 0]), (-2, [0, 2, 0]), (1, [1, 0, 0])]);
+        let groebner = GroebnerBasis::new(&[f1.clone(), f2.clone()]);
+        let other = GroebnerBasis::new(&[
+            f2.clone(),
+            f1.clone() + f2.clone() * poly(&[(3, [0, 1, 0])]),
+            f1 * poly(&[(1, [0, 0, 1])]),
+        ]);
+        assert_eq!(groebner, other);
+    }
+}
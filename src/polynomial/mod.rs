@@ -0,0 +1,84 @@
+//! Polynomials over the algebraic structures of this crate.
+//!
+//! [`DensePoly`] stores every coefficient up to the degree, while [`SparsePoly`] only stores the
+//! non-zero terms, which suits polynomials such as xⁿ - 1 of large degree and few terms. Both are
+//! commutative rings whenever their coefficients are, and over a [`Field`](crate::Field) they are
+//! Euclidean domains implementing [`Polynomial`](crate::Polynomial).
+//!
+//! [`MPoly`] holds polynomials in several variables, with terms sorted by a [`MonomialOrder`].
+//! Over a field, [`GroebnerBasis`] computes canonical bases of their ideals, for reduction and
+//! ideal membership.
+
+mod dense;
+mod groebner;
+mod multiplication;
+mod multivariate;
+mod sparse;
+
+pub use dense::DensePoly;
+pub use groebner::GroebnerBasis;
+pub use multivariate::{GrLex, GrevLex, Lex, MPoly, Monomial, MonomialOrder};
+pub use sparse::SparsePoly;
+
+use crate::Ring;
+use std::fmt;
+
+/// Writes the non-zero `terms`, given from the highest degree down, as in `2x^2 + x + 5`.
+fn write_terms<'a, R: Ring + fmt::Display + 'a>(
+    f: &mut fmt::Formatter<'_>,
+    terms: impl Iterator<Item = (usize, &'a R)>,
+) -> fmt::Result {
+    let mut first = true;
+    for (degree, c) in terms {
+        if !first {
+            write!(f, " + ")?;
+        }
+        first = false;
+        let coefficient = if degree > 0 && c.is_one() {
+            String::new()
+        } else {
+            c.to_string()
+        };
+        match degree {
+            0 => write!(f, "{coefficient}")?,
+            1 => write!(f, "{coefficient}x")?,
+            _ => write!(f, "{coefficient}x^{degree}")?,
+        }
+    }
+    if first {
+        write!(f, "0")?;
+    }
+    Ok(())
+}
+
+/// Returns `x` raised to the power `exp` by repeated squaring.
+pub(crate) fn pow<R: Ring>(x: &R, mut exp: usize) -> R {
+    let mut base = x.clone();
+    let mut result = R::one();
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result *= base.clone();
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base *= base.clone();
+        }
+    }
+    result
+}
+
+/// Returns the integer multiple `k · c` by repeated doubling.
+pub(crate) fn multiple<R: Ring>(c: &R, mut k: usize) -> R {
+    let mut base = c.clone();
+    let mut result = R::zero();
+    while k > 0 {
+        if k & 1 == 1 {
+            result += base.clone();
+        }
+        k >>= 1;
+        if k > 0 {
+            base += base.clone();
+        }
+    }
+    result
+}
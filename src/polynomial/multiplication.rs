@@ -0,0 +1,324 @@
+//! Multiplication algorithms on coefficient slices, lowest degree first.
+//!
+//! Each function returns the `a.len() + b.len() - 1` coefficients of the product, or none if
+//! either operand is empty, and keeps every coefficient product in the order aᵢ · bⱼ.
+//!
+//! Karatsuba and Toom-3 add and subtract intermediate values which the definition never forms.
+//! Over a [bounded](crate::AssociativeAddition::BOUNDED) ring such as `Checked<i64>` these could
+//! overflow although the product fits, so both multiply by the definition there.
+
+use crate::{ExactDivision, Ring};
+
+/// The length of the shorter operand below which Karatsuba multiplies directly.
+pub(super) const KARATSUBA_THRESHOLD: usize = 32;
+
+/// The length of the shorter operand below which Toom-3 falls back to Karatsuba.
+pub(super) const TOOM3_THRESHOLD: usize = 96;
+
+/// Multiplies with the algorithm suited to the length of the shorter operand: by the definition
+/// below 32 coefficients, with Karatsuba below 96, and with Toom-3 from there when R divides
+/// exactly by 2 and 3. Other rings keep using Karatsuba.
+pub(super) fn multiply<R: ExactDivision>(a: &[R], b: &[R]) -> Vec<R> {
+    if R::BOUNDED || a.len().min(b.len()) < TOOM3_THRESHOLD {
+        return karatsuba(a, b);
+    }
+    match exact_two(a, b) {
+        Some(two) => toom3(a, b, &two),
+        None => karatsuba(a, b),
+    }
+}
+
+/// Returns 2 if R divides exactly by 2 and 3, and `None` otherwise.
+///
+/// The constants are built on the differences c - c of the coefficients, so that elements
+/// carrying a runtime modulus answer for the ring of the operands.
+fn exact_two<R: ExactDivision>(a: &[R], b: &[R]) -> Option<R> {
+    let zero = a
+        .iter()
+        .chain(b)
+        .fold(R::zero(), |zero, c| zero + (c.clone() - c.clone()));
+    let one = zero + R::one();
+    let two = one.clone() + one.clone();
+    let three = two.clone() + one.clone();
+    let divides = two.div_exact(2).as_ref() == Some(&one) && three.div_exact(3) == Some(one);
+    divides.then_some(two)
+}
+
+/// Multiplies by the definition (ab)ₖ = Σᵢ₊ⱼ₌ₖ aᵢbⱼ, in O(nm).
+pub(super) fn schoolbook<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![R::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] += x.clone() * y.clone();
+        }
+    }
+    product
+}
+
+/// Multiplies with Karatsuba's algorithm, in O(n^1.58), unless R is bounded.
+///
+/// Splitting a = a₀ + a₁xʰ and b = b₀ + b₁xʰ, the product needs three half-size products:
+/// ab = a₀b₀ + ((a₀ + a₁)(b₀ + b₁) - a₀b₀ - a₁b₁)xʰ + a₁b₁x²ʰ
+pub(super) fn karatsuba<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    if R::BOUNDED || a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+    let half = a.len().max(b.len()).div_ceil(2);
+    if a.len() <= half || b.len() <= half {
+        return unbalanced(a, b, karatsuba);
+    }
+
+    let (a0, a1) = a.split_at(half);
+    let (b0, b1) = b.split_at(half);
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let mut z1 = karatsuba(&add(a0, a1), &add(b0, b1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    let mut product = vec![R::zero(); a.len() + b.len() - 1];
+    add_at(&mut product, &z0, 0);
+    add_at(&mut product, &z1, half);
+    add_at(&mut product, &z2, 2 * half);
+    product
+}
+
+/// Multiplies with the Toom-Cook algorithm of three parts, in O(n^1.46), for a ring that
+/// divides exactly by 2 and 3.
+///
+/// Splitting a = a₀ + a₁y + a₂y² and b alike with y = xᵏ, the product is the polynomial of
+/// degree 4 in y interpolated from its values at 0, 1, -1, -2 and ∞, using five third-size
+/// products. The interpolation divides exactly by 2 and 3.
+fn toom3<R: ExactDivision>(a: &[R], b: &[R], two: &R) -> Vec<R> {
+    if a.len().min(b.len()) < TOOM3_THRESHOLD {
+        return karatsuba(a, b);
+    }
+    let k = a.len().max(b.len()).div_ceil(3);
+    if a.len() <= 2 * k || b.len() <= 2 * k {
+        return unbalanced(a, b, |a, b| toom3(a, b, two));
+    }
+
+    let length = a.len() + b.len() - 1;
+    let product = |p: &[R], q: &[R]| toom3(p, q, two);
+    let (a, b) = (split3(a, k), split3(b, k));
+    let (p, q) = (evaluate3(&a, two), evaluate3(&b, two));
+
+    // The values of the product at 0, 1, -1, -2 and ∞.
+    let r0 = product(a[0], b[0]);
+    let r1 = product(&p[0], &q[0]);
+    let r_1 = product(&p[1], &q[1]);
+    let r_2 = product(&p[2], &q[2]);
+    let r_inf = product(a[2], b[2]);
+
+    // Bodrato's interpolation sequence.
+    let mut c3 = divide(&sub(&r_2, &r1), 3);
+    let mut c1 = divide(&sub(&r1, &r_1), 2);
+    let mut c2 = sub(&r_1, &r0);
+    c3 = add(&divide(&sub(&c2, &c3), 2), &add(&r_inf, &r_inf));
+    c2 = sub(&add(&c2, &c1), &r_inf);
+    sub_assign(&mut c1, &c3);
+
+    let mut result = vec![R::zero(); length];
+    for (i, c) in [&r0, &c1, &c2, &c3, &r_inf].into_iter().enumerate() {
+        add_at(&mut result, c, i * k);
+    }
+    // The intermediate coefficients may extend past the product with zeros.
+    result.truncate(length);
+    result
+}
+
+/// Multiplies operands of very different lengths by cutting the longer one into pieces of the
+/// length of the shorter one, and multiplying each piece with `multiply`.
+fn unbalanced<R: Ring>(a: &[R], b: &[R], multiply: impl Fn(&[R], &[R]) -> Vec<R>) -> Vec<R> {
+    let mut product = vec![R::zero(); a.len() + b.len() - 1];
+    if a.len() >= b.len() {
+        for (i, piece) in a.chunks(b.len()).enumerate() {
+            add_at(&mut product, &multiply(piece, b), i * b.len());
+        }
+    } else {
+        for (i, piece) in b.chunks(a.len()).enumerate() {
+            add_at(&mut product, &multiply(a, piece), i * a.len());
+        }
+    }
+    product
+}
+
+/// Splits `a` into the three parts of length `k` of a = a₀ + a₁xᵏ + a₂x²ᵏ.
+fn split3<R>(a: &[R], k: usize) -> [&[R]; 3] {
+    let (a0, rest) = a.split_at(k);
+    let (a1, a2) = rest.split_at(k);
+    [a0, a1, a2]
+}
+
+/// Returns the values a₀ + a₁ + a₂, a₀ - a₁ + a₂ and a₀ - 2a₁ + 4a₂ at 1, -1 and -2.
+fn evaluate3<R: Ring>(a: &[&[R]; 3], two: &R) -> [Vec<R>; 3] {
+    let even = add(a[0], a[2]);
+    let at_1 = add(&even, a[1]);
+    let at_minus_1 = sub(&even, a[1]);
+    // a₀ - 2a₁ + 4a₂ = 2(a₀ - a₁ + a₂ + a₂) - a₀
+    let doubled = scale(&add(&at_minus_1, a[2]), two);
+    let at_minus_2 = sub(&doubled, a[0]);
+    [at_1, at_minus_1, at_minus_2]
+}
+
+/// Returns a + b, with the length of the longer operand.
+fn add<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut sum = a.to_vec();
+    add_at(&mut sum, b, 0);
+    sum
+}
+
+/// Returns a - b, with the length of the longer operand.
+fn sub<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut difference = a.to_vec();
+    sub_assign(&mut difference, b);
+    difference
+}
+
+/// Adds `b` to `a` from the offset `at`, extending `a` as needed.
+fn add_at<R: Ring>(a: &mut Vec<R>, b: &[R], at: usize) {
+    if a.len() < at + b.len() {
+        a.resize(at + b.len(), R::zero());
+    }
+    for (x, y) in a[at..].iter_mut().zip(b) {
+        *x += y.clone();
+    }
+}
+
+/// Subtracts `b` from `a`, extending `a` as needed.
+fn sub_assign<R: Ring>(a: &mut Vec<R>, b: &[R]) {
+    if a.len() < b.len() {
+        a.resize(b.len(), R::zero());
+    }
+    for (x, y) in a.iter_mut().zip(b) {
+        *x -= y.clone();
+    }
+}
+
+/// Returns every coefficient multiplied by `factor`.
+fn scale<R: Ring>(a: &[R], factor: &R) -> Vec<R> {
+    a.iter().map(|x| x.clone() * factor.clone()).collect()
+}
+
+/// Returns every coefficient divided exactly by `divisor`.
+fn divide<R: ExactDivision>(a: &[R], divisor: u32) -> Vec<R> {
+    a.iter()
+        .map(|x| {
+            x.div_exact(divisor)
+                .expect("the Toom-3 interpolation divides exactly")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Checked, DynPrimeField, FinitePrimeField, Gf256, PrimeFieldContext, PrimeFieldParams, Zn,
+    };
+    use std::num::Wrapping;
+
+    /// The prime 2⁶⁴ - 2³² + 1.
+    struct Goldilocks;
+
+    impl PrimeFieldParams<1> for Goldilocks {
+        const MODULUS: [u64; 1] = [0xFFFFFFFF00000001];
+    }
+
+    /// The shapes of operands: balanced at and above the Toom-3 threshold, unbalanced, and far
+    /// apart in length.
+    const SHAPES: [(usize, usize); 5] = [(96, 96), (97, 150), (300, 200), (96, 400), (250, 250)];
+
+    /// Returns a generator of pseudo-random values.
+    fn values() -> impl FnMut() -> u64 {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        }
+    }
+
+    /// Checks `multiply` against the definition on every shape.
+    fn check_against_schoolbook<R: ExactDivision + std::fmt::Debug>(
+        mut element: impl FnMut() -> R,
+    ) {
+        for (m, n) in SHAPES {
+            let a: Vec<R> = (0..m).map(|_| element()).collect();
+            let b: Vec<R> = (0..n).map(|_| element()).collect();
+            assert_eq!(
+                multiply(&a, &b),
+                schoolbook(&a, &b),
+                "{m} × {n} coefficients"
+            );
+        }
+    }
+
+    #[test]
+    fn toom3_applies_when_2_and_3_divide_exactly() {
+        fn applies<R: ExactDivision>(c: R) -> bool {
+            exact_two(std::slice::from_ref(&c), &[]).is_some()
+        }
+        let context = PrimeFieldContext::new([101]).unwrap();
+
+        assert!(applies(Checked(-5i64)));
+        assert!(applies(Zn::<65537>::new(7)));
+        assert!(applies(FinitePrimeField::<Goldilocks, 1>::from(7u64)));
+        assert!(applies(DynPrimeField::from_u64(&context, 7)));
+        // 3 = 0 in characteristic 3, 2 is a zero divisor of ℤ/2⁶⁴, and 2 = 0 in GF(2⁸).
+        assert!(!applies(Zn::<3>::new(1)));
+        assert!(!applies(Zn::<6>::new(1)));
+        assert!(!applies(Wrapping(5u64)));
+        assert!(!applies(Gf256::new(5)));
+    }
+
+    #[test]
+    fn toom3_products_match_the_definition() {
+        let mut next = values();
+        check_against_schoolbook(|| Zn::<65537>::new(next()));
+        let mut next = values();
+        check_against_schoolbook(|| FinitePrimeField::<Goldilocks, 1>::from(next()));
+        let context = PrimeFieldContext::new([
+            0x3C208C16D87CFD47,
+            0x97816A916871CA8D,
+            0xB85045B68181585D,
+            0x30644E72E131A029,
+        ])
+        .unwrap();
+        let mut next = values();
+        check_against_schoolbook(|| DynPrimeField::from_u64(&context, next()));
+    }
+
+    #[test]
+    fn rings_without_exact_division_fall_back_to_karatsuba() {
+        let mut next = values();
+        check_against_schoolbook(|| Zn::<3>::new(next()));
+        let mut next = values();
+        check_against_schoolbook(|| Wrapping(next()));
+        let mut next = values();
+        check_against_schoolbook(|| Gf256::new(next() as u8));
+    }
+
+    #[test]
+    fn bounded_rings_multiply_by_the_definition() {
+        // The halves of a sum to 2⁶³, which overflows, while every coefficient of ab is 0 or
+        // ±2⁶² and every partial sum of the definition fits.
+        let a = vec![Checked(1i64 << 62); 64];
+        let mut b = vec![Checked(0i64); 64];
+        b[0] = Checked(1);
+        b[1] = Checked(-1);
+        let product = multiply(&a, &b);
+        assert_eq!(product, schoolbook(&a, &b));
+        assert_eq!(karatsuba(&a, &b), product);
+        assert_eq!(product[0], Checked(1 << 62));
+        assert!(product[1..64].iter().all(|c| c.0 == 0));
+        assert_eq!(product[64], Checked(-(1 << 62)));
+
+        let mut next = values();
+        check_against_schoolbook(|| Checked((next() % 2001) as i64 - 1000));
+    }
+}
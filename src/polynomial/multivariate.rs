@@ -0,0 +1,835 @@
+//! Multivariate polynomials, with their terms sorted by a monomial order.
+
+use super::{multiple, pow};
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    Distributive, ExactDivision, Field, NoZeroDivisors, Ring,
+};
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A monomial x₀^e₀ x₁^e₁ ⋯ xₙ^eₙ, held as its exponent vector.
+///
+/// Trailing zero exponents are dropped, so that a monomial does not depend on the number of
+/// variables it is considered in. The empty exponent vector is the monomial 1.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Monomial(Vec<usize>);
+
+impl Monomial {
+    /// Creates the monomial with the given exponents, of x₀ first.
+    pub fn new(mut exponents: Vec<usize>) -> Self {
+        while exponents.last() == Some(&0) {
+            exponents.pop();
+        }
+        Monomial(exponents)
+    }
+
+    /// Creates the monomial consisting of the single variable xᵢ.
+    pub fn variable(i: usize) -> Self {
+        let mut exponents = vec![0; i + 1];
+        exponents[i] = 1;
+        Monomial(exponents)
+    }
+
+    /// Returns the exponents, of x₀ first, without trailing zeros.
+    pub fn exponents(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Returns the exponent of xᵢ.
+    pub fn exponent(&self, i: usize) -> usize {
+        self.0.get(i).copied().unwrap_or(0)
+    }
+
+    /// Returns the total degree, the sum of the exponents.
+    pub fn total_degree(&self) -> usize {
+        self.0.iter().sum()
+    }
+
+    /// Returns whether `self` divides `other`, that is whether no exponent of `self` exceeds
+    /// the corresponding exponent of `other`.
+    pub fn divides(&self, other: &Monomial) -> bool {
+        self.0.len() <= other.0.len() && self.0.iter().zip(&other.0).all(|(a, b)| a <= b)
+    }
+
+    /// Returns `self / divisor`, or `None` if `divisor` does not divide `self`.
+    pub fn checked_div(&self, divisor: &Monomial) -> Option<Monomial> {
+        divisor.divides(self).then(|| {
+            let mut exponents = self.0.clone();
+            for (a, b) in exponents.iter_mut().zip(&divisor.0) {
+                *a -= b;
+            }
+            Monomial::new(exponents)
+        })
+    }
+
+    /// Returns the least common multiple, the monomial of the largest exponents of both.
+    pub fn lcm(&self, other: &Monomial) -> Monomial {
+        let n = self.0.len().max(other.0.len());
+        Monomial(
+            (0..n)
+                .map(|i| self.exponent(i).max(other.exponent(i)))
+                .collect(),
+        )
+    }
+
+    /// Returns whether `self` and `other` share no variable.
+    pub fn is_coprime(&self, other: &Monomial) -> bool {
+        self.0.iter().zip(&other.0).all(|(&a, &b)| a == 0 || b == 0)
+    }
+}
+
+impl Mul for Monomial {
+    type Output = Self;
+
+    /// Multiplies the monomials by adding their exponents.
+    fn mul(self, rhs: Self) -> Self {
+        let (mut long, short) = if self.0.len() >= rhs.0.len() {
+            (self.0, rhs.0)
+        } else {
+            (rhs.0, self.0)
+        };
+        for (a, b) in long.iter_mut().zip(short) {
+            *a += b;
+        }
+        Monomial(long)
+    }
+}
+
+impl One for Monomial {
+    fn one() -> Self {
+        Monomial(Vec::new())
+    }
+}
+
+impl fmt::Display for Monomial {
+    /// Formats the monomial as in `x0^2*x2`, or `1` for the empty monomial.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut factors = self.0.iter().enumerate().filter(|(_, &e)| e > 0);
+        let Some(first) = factors.next() else {
+            return write!(f, "1");
+        };
+        let write_factor = |f: &mut fmt::Formatter<'_>, (i, &e): (usize, &usize)| match e {
+            1 => write!(f, "x{i}"),
+            _ => write!(f, "x{i}^{e}"),
+        };
+        write_factor(f, first)?;
+        for factor in factors {
+            write!(f, "*")?;
+            write_factor(f, factor)?;
+        }
+        Ok(())
+    }
+}
+
+/// A monomial order: a total order on monomials compatible with multiplication.
+///
+/// # Mathematical Definition
+/// A monomial order is a well-order < on the monomials such that a < b implies ac < bc for every
+/// monomial c. It determines the leading term of a multivariate polynomial, and so the result of
+/// multivariate division. All the orders here rank x₀ > x₁ > x₂ > ...
+pub trait MonomialOrder {
+    /// Compares two monomials.
+    fn compare(a: &Monomial, b: &Monomial) -> Ordering;
+}
+
+/// The lexicographic order: compares the exponent of x₀, then of x₁, and so on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Lex;
+
+/// The graded lexicographic order: compares total degrees, then breaks ties by [`Lex`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GrLex;
+
+/// The graded reverse lexicographic order: compares total degrees, then ranks the monomial with
+/// the smaller exponent in the last variable where they differ as the larger one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GrevLex;
+
+impl MonomialOrder for Lex {
+    fn compare(a: &Monomial, b: &Monomial) -> Ordering {
+        // Without trailing zeros, a shorter exponent vector is padded with zeros.
+        a.0.cmp(&b.0)
+    }
+}
+
+impl MonomialOrder for GrLex {
+    fn compare(a: &Monomial, b: &Monomial) -> Ordering {
+        a.total_degree()
+            .cmp(&b.total_degree())
+            .then_with(|| Lex::compare(a, b))
+    }
+}
+
+impl MonomialOrder for GrevLex {
+    fn compare(a: &Monomial, b: &Monomial) -> Ordering {
+        a.total_degree().cmp(&b.total_degree()).then_with(|| {
+            let n = a.0.len().max(b.0.len());
+            (0..n)
+                .rev()
+                .map(|i| b.exponent(i).cmp(&a.exponent(i)))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+}
+
+/// A monomial sorted by the order `O`, as a key of the terms of an [`MPoly`].
+struct Key<O>(Monomial, PhantomData<fn() -> O>);
+
+impl<O> Key<O> {
+    fn new(monomial: Monomial) -> Self {
+        Key(monomial, PhantomData)
+    }
+}
+
+impl<O> Clone for Key<O> {
+    fn clone(&self) -> Self {
+        Key::new(self.0.clone())
+    }
+}
+
+impl<O> PartialEq for Key<O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<O> Eq for Key<O> {}
+
+impl<O: MonomialOrder> PartialOrd for Key<O> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<O: MonomialOrder> Ord for Key<O> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        O::compare(&self.0, &other.0)
+    }
+}
+
+/// Represents a polynomial in the variables x₀, x₁, ... over a ring R.
+///
+/// # Mathematical Definition
+/// The polynomial ring R[x₀, ..., xₙ] consists of the finite sums of terms c·m, with c ∈ R and m
+/// a monomial, multiplied by (c·m)(d·m') = (cd)·(mm') and distributivity.
+///
+/// # Properties
+/// - R[x₀, ..., xₙ] is a commutative ring whenever R is, and an integral domain whenever R is
+/// - It is not a Euclidean domain for two or more variables, even over a field: the ideal
+///   (x₀, x₁) is not principal
+///
+/// The terms are kept sorted by the monomial order `O`, which decides the leading term. The
+/// number of variables is not fixed: it grows with the monomials in use.
+///
+/// # Example
+/// ```
+/// use noether::{Fp, GrevLex, MPoly, Monomial};
+/// use num_traits::One;
+///
+/// type F = Fp<101>;
+/// type P = MPoly<F, GrevLex>;
+///
+/// let x = P::variable(0);
+/// let y = P::variable(1);
+///
+/// // f = x²y + 3y² + 1
+/// let f = x.clone() * x * y.clone() + P::from(F::new(3)) * y.clone() * y + P::one();
+///
+/// assert_eq!(f.total_degree(), 3);
+/// assert_eq!(f.leading_monomial(), Some(&Monomial::new(vec![2, 1])));
+/// assert_eq!(f.evaluate(&[F::new(2), F::new(5)]), F::new(96));
+/// assert_eq!(f.partial_derivative(1).to_string(), "x0^2 + 6*x1");
+/// ```
+pub struct MPoly<R, O> {
+    terms: BTreeMap<Key<O>, R>,
+}
+
+impl<R: Ring, O: MonomialOrder> MPoly<R, O> {
+    /// Creates the polynomial with the given `(monomial, coefficient)` terms.
+    ///
+    /// Terms with equal monomials are added together, and zero coefficients are dropped.
+    pub fn new(terms: impl IntoIterator<Item = (Monomial, R)>) -> Self {
+        let mut polynomial = Self::zero();
+        for (monomial, coefficient) in terms {
+            polynomial.add_term(monomial, coefficient);
+        }
+        polynomial
+    }
+
+    /// Creates the term `coefficient · monomial`.
+    pub fn term(coefficient: R, monomial: Monomial) -> Self {
+        Self::new([(monomial, coefficient)])
+    }
+
+    /// Creates the variable xᵢ.
+    pub fn variable(i: usize) -> Self {
+        Self::term(R::one(), Monomial::variable(i))
+    }
+
+    /// Returns the non-zero terms as `(monomial, coefficient)` pairs, in increasing order.
+    pub fn terms(&self) -> impl DoubleEndedIterator<Item = (&Monomial, &R)> + '_ {
+        self.terms.iter().map(|(key, c)| (&key.0, c))
+    }
+
+    /// Returns the number of non-zero terms.
+    pub fn term_count(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the term with the greatest monomial, or `None` for the zero polynomial.
+    pub fn leading_term(&self) -> Option<(&Monomial, &R)> {
+        self.terms().next_back()
+    }
+
+    /// Returns the greatest monomial, or `None` for the zero polynomial.
+    pub fn leading_monomial(&self) -> Option<&Monomial> {
+        self.leading_term().map(|(monomial, _)| monomial)
+    }
+
+    /// Returns the coefficient of the greatest monomial, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&R> {
+        self.leading_term().map(|(_, c)| c)
+    }
+
+    /// Returns the coefficient of `monomial`.
+    pub fn coefficient(&self, monomial: &Monomial) -> R {
+        self.terms
+            .get(&Key::new(monomial.clone()))
+            .cloned()
+            .unwrap_or_else(R::zero)
+    }
+
+    /// Returns the largest total degree of a term, or 0 for the zero polynomial.
+    pub fn total_degree(&self) -> usize {
+        self.terms()
+            .map(|(monomial, _)| monomial.total_degree())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of variables in use: one more than the index of the last variable
+    /// that appears.
+    pub fn variable_count(&self) -> usize {
+        self.terms()
+            .map(|(monomial, _)| monomial.0.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluates the polynomial at the point with coordinates `point`, x₀ first.
+    ///
+    /// # Panics
+    /// Panics if a variable in use has no coordinate.
+    pub fn evaluate(&self, point: &[R]) -> R {
+        assert!(
+            self.variable_count() <= point.len(),
+            "the point has fewer coordinates than the polynomial has variables"
+        );
+        self.terms().fold(R::zero(), |acc, (monomial, c)| {
+            let value = monomial
+                .0
+                .iter()
+                .zip(point)
+                .fold(c.clone(), |value, (&e, x)| value * pow(x, e));
+            acc + value
+        })
+    }
+
+    /// Returns the formal partial derivative with respect to xᵢ.
+    pub fn partial_derivative(&self, i: usize) -> Self {
+        Self::new(self.terms().filter_map(|(monomial, c)| {
+            let e = monomial.exponent(i);
+            (e > 0).then(|| {
+                let mut exponents = monomial.0.clone();
+                exponents[i] -= 1;
+                (Monomial::new(exponents), multiple(c, e))
+            })
+        }))
+    }
+
+    /// Returns the polynomial with every coefficient multiplied by `scalar`.
+    pub fn scale_by(&self, scalar: &R) -> Self {
+        Self::new(
+            self.terms()
+                .map(|(monomial, c)| (monomial.clone(), c.clone() * scalar.clone())),
+        )
+    }
+
+    /// Adds `coefficient · monomial`, removing the term if it cancels.
+    fn add_term(&mut self, monomial: Monomial, coefficient: R) {
+        match self.terms.entry(Key::new(monomial)) {
+            Entry::Vacant(entry) => {
+                if !coefficient.is_zero() {
+                    entry.insert(coefficient);
+                }
+            }
+            Entry::Occupied(mut entry) => {
+                *entry.get_mut() += coefficient;
+                if entry.get().is_zero() {
+                    entry.remove();
+                }
+            }
+        }
+    }
+}
+
+impl<F: Field, O: MonomialOrder> MPoly<F, O> {
+    /// Divides `self` by the polynomials `divisors`, returning the quotients and the remainder.
+    ///
+    /// This is the multivariate division algorithm: it repeatedly cancels the leading term of
+    /// the dividend with the first divisor whose leading monomial divides it, and moves the
+    /// leading term to the remainder when there is none. The result satisfies
+    /// `self = Σ qᵢ · divisorsᵢ + r`, with no term of `r` divisible by a leading monomial of the
+    /// divisors. Unlike in one variable, the remainder depends on the order of the divisors
+    /// unless they form a [`GroebnerBasis`](super::GroebnerBasis).
+    ///
+    /// Zero divisors are skipped, and get a zero quotient.
+    pub fn div_rem(&self, divisors: &[Self]) -> (Vec<Self>, Self) {
+        let leading: Vec<_> = divisors
+            .iter()
+            .map(|g| {
+                g.leading_term()
+                    .map(|(monomial, c)| (monomial.clone(), c.clone().inv()))
+            })
+            .collect();
+        let mut quotients = vec![Self::zero(); divisors.len()];
+        let mut remainder = Self::zero();
+        let mut dividend = self.clone();
+        while let Some((monomial, c)) = dividend.leading_term() {
+            let (monomial, c) = (monomial.clone(), c.clone());
+            let division = leading.iter().enumerate().find_map(|(i, lead)| {
+                let (lead_monomial, lead_inv) = lead.as_ref()?;
+                let shift = monomial.checked_div(lead_monomial)?;
+                Some((i, shift, c.clone() * lead_inv.clone()))
+            });
+            match division {
+                Some((i, shift, factor)) => {
+                    dividend.sub_shifted(&factor, &shift, &divisors[i]);
+                    quotients[i].add_term(shift, factor);
+                }
+                None => {
+                    dividend.terms.remove(&Key::new(monomial.clone()));
+                    remainder.add_term(monomial, c);
+                }
+            }
+        }
+        (quotients, remainder)
+    }
+
+    /// Returns the remainder of the division of `self` by `divisors`.
+    ///
+    /// See [`div_rem`](Self::div_rem).
+    pub fn reduce(&self, divisors: &[Self]) -> Self {
+        self.div_rem(divisors).1
+    }
+
+    /// Returns the S-polynomial of `self` and `other`, the combination that cancels their
+    /// leading terms: (L / lt(f)) · f - (L / lt(g)) · g with L the lcm of the leading monomials.
+    ///
+    /// Returns zero if either polynomial is zero.
+    pub fn s_polynomial(&self, other: &Self) -> Self {
+        let (Some((a, c)), Some((b, d))) = (self.leading_term(), other.leading_term()) else {
+            return Self::zero();
+        };
+        let lcm = a.lcm(b);
+        let left = Self::term(c.clone().inv(), lcm.checked_div(a).unwrap());
+        let right = Self::term(d.clone().inv(), lcm.checked_div(b).unwrap());
+        left * self.clone() - right * other.clone()
+    }
+
+    /// Returns the polynomial divided by its leading coefficient, or zero for zero.
+    pub fn monic(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(leading) => self.scale_by(&leading.clone().inv()),
+            None => Self::zero(),
+        }
+    }
+
+    /// Subtracts `factor · shift · g`, whose leading term cancels that of `self`.
+    fn sub_shifted(&mut self, factor: &F, shift: &Monomial, g: &Self) {
+        let mut terms = g.terms().rev();
+        if let Some((monomial, _)) = terms.next() {
+            // The leading term cancels by construction.
+            self.terms
+                .remove(&Key::new(monomial.clone() * shift.clone()));
+        }
+        for (monomial, c) in terms {
+            self.add_term(
+                monomial.clone() * shift.clone(),
+                -(factor.clone() * c.clone()),
+            );
+        }
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> From<R> for MPoly<R, O> {
+    /// Embeds `value` as a constant polynomial.
+    fn from(value: R) -> Self {
+        Self::term(value, Monomial::one())
+    }
+}
+
+// Derived impls would require the order type to implement the traits as well.
+
+impl<R: Clone, O> Clone for MPoly<R, O> {
+    fn clone(&self) -> Self {
+        MPoly {
+            terms: self.terms.clone(),
+        }
+    }
+}
+
+impl<R: PartialEq, O> PartialEq for MPoly<R, O> {
+    fn eq(&self, other: &Self) -> bool {
+        // Both maps are sorted by the same order.
+        self.terms.len() == other.terms.len()
+            && self
+                .terms
+                .iter()
+                .zip(&other.terms)
+                .all(|((a, c), (b, d))| a == b && c == d)
+    }
+}
+
+impl<R: Eq, O> Eq for MPoly<R, O> {}
+
+impl<R: Hash, O> Hash for MPoly<R, O> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.terms.len());
+        for (key, c) in &self.terms {
+            key.0.hash(state);
+            c.hash(state);
+        }
+    }
+}
+
+impl<R: fmt::Debug, O> fmt::Debug for MPoly<R, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.terms.iter().map(|(key, c)| (&key.0, c)))
+            .finish()
+    }
+}
+
+impl<R: Ring + fmt::Display, O: MonomialOrder> fmt::Display for MPoly<R, O> {
+    /// Formats the polynomial from the leading term down, as in `3*x0^2*x1 + x2 + 5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (monomial, c) in self.terms().rev() {
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+            if monomial.0.is_empty() {
+                write!(f, "{c}")?;
+            } else if c.is_one() {
+                write!(f, "{monomial}")?;
+            } else {
+                write!(f, "{c}*{monomial}")?;
+            }
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> Add for MPoly<R, O> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> AddAssign for MPoly<R, O> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (key, c) in rhs.terms {
+            self.add_term(key.0, c);
+        }
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> Neg for MPoly<R, O> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        MPoly {
+            terms: self.terms.into_iter().map(|(key, c)| (key, -c)).collect(),
+        }
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> Sub for MPoly<R, O> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> SubAssign for MPoly<R, O> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self += -rhs;
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> Mul for MPoly<R, O> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut product = Self::zero();
+        for (a, c) in self.terms() {
+            for (b, d) in rhs.terms() {
+                product.add_term(a.clone() * b.clone(), c.clone() * d.clone());
+            }
+        }
+        product
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> MulAssign for MPoly<R, O> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::replace(self, Self::zero()) * rhs;
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> Zero for MPoly<R, O> {
+    fn zero() -> Self {
+        MPoly {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<R: Ring, O: MonomialOrder> One for MPoly<R, O> {
+    fn one() -> Self {
+        Self::from(R::one())
+    }
+}
+
+impl<R: CommutativeAddition, O> CommutativeAddition for MPoly<R, O> {}
+impl<R: AssociativeAddition, O> AssociativeAddition for MPoly<R, O> {
+    const BOUNDED: bool = R::BOUNDED;
+}
+impl<R: CommutativeMultiplication, O> CommutativeMultiplication for MPoly<R, O> {}
+impl<R: AssociativeMultiplication, O> AssociativeMultiplication for MPoly<R, O> {}
+impl<R: Distributive, O> Distributive for MPoly<R, O> {}
+impl<R: NoZeroDivisors, O> NoZeroDivisors for MPoly<R, O> {}
+
+/// Divides every coefficient.
+impl<R: ExactDivision, O: MonomialOrder> ExactDivision for MPoly<R, O> {
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        let terms = self
+            .terms()
+            .map(|(monomial, c)| Some((monomial.clone(), c.div_exact(n)?)));
+        terms.collect::<Option<Vec<_>>>().map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_integral_domain;
+    use crate::Fp;
+
+    type F = Fp<101>;
+
+    fn m(exponents: [usize; 3]) -> Monomial {
+        Monomial::new(exponents.to_vec())
+    }
+
+    fn c(value: i64) -> F {
+        F::new(value.rem_euclid(101) as u64)
+    }
+
+    /// Returns the polynomial with the given `(coefficient, exponents)` terms.
+    fn poly<O: MonomialOrder>(terms: &[(i64, [usize; 3])]) -> MPoly<F, O> {
+        MPoly::new(terms.iter().map(|&(a, e)| (m(e), c(a))))
+    }
+
+    /// The leading monomials of the terms of 4xy²z + 4z² - 5x³ + 7x²z², from the largest down.
+    fn sorted<O: MonomialOrder>() -> Vec<Monomial> {
+        let f = poly::<O>(&[
+            (4, [1, 2, 1]),
+            (4, [0, 0, 2]),
+            (-5, [3, 0, 0]),
+            (7, [2, 0, 2]),
+        ]);
+        f.terms()
+            .rev()
+            .map(|(monomial, _)| monomial.clone())
+            .collect()
+    }
+
+    // The examples of Cox, Little and O'Shea, Ideals, Varieties, and Algorithms, §2.2.
+
+    #[test]
+    fn lex_compares_the_first_differing_exponent() {
+        assert_eq!(
+            Lex::compare(&m([1, 2, 0]), &m([0, 3, 4])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            Lex::compare(&m([3, 2, 4]), &m([3, 2, 1])),
+            Ordering::Greater
+        );
+        assert_eq!(Lex::compare(&m([0, 0, 9]), &m([0, 1, 0])), Ordering::Less);
+        assert_eq!(Lex::compare(&m([2, 1, 0]), &m([2, 1, 0])), Ordering::Equal);
+        assert_eq!(
+            sorted::<Lex>(),
+            [m([3, 0, 0]), m([2, 0, 2]), m([1, 2, 1]), m([0, 0, 2])]
+        );
+    }
+
+    #[test]
+    fn grlex_compares_total_degrees_first() {
+        assert_eq!(
+            GrLex::compare(&m([1, 2, 3]), &m([3, 2, 0])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            GrLex::compare(&m([1, 2, 4]), &m([1, 1, 5])),
+            Ordering::Greater
+        );
+        assert_eq!(GrLex::compare(&m([5, 0, 0]), &m([0, 0, 6])), Ordering::Less);
+        assert_eq!(
+            sorted::<GrLex>(),
+            [m([2, 0, 2]), m([1, 2, 1]), m([3, 0, 0]), m([0, 0, 2])]
+        );
+    }
+
+    #[test]
+    fn grevlex_prefers_smaller_exponents_in_the_last_variables() {
+        assert_eq!(
+            GrevLex::compare(&m([4, 7, 1]), &m([4, 2, 3])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            GrevLex::compare(&m([1, 5, 2]), &m([4, 1, 3])),
+            Ordering::Greater
+        );
+        // Both have degree 4: grlex prefers x²z², grevlex xy²z.
+        assert_eq!(
+            GrLex::compare(&m([2, 0, 2]), &m([1, 2, 1])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            GrevLex::compare(&m([2, 0, 2]), &m([1, 2, 1])),
+            Ordering::Less
+        );
+        assert_eq!(
+            sorted::<GrevLex>(),
+            [m([1, 2, 1]), m([2, 0, 2]), m([3, 0, 0]), m([0, 0, 2])]
+        );
+    }
+
+    #[test]
+    fn orders_are_compatible_with_multiplication() {
+        let monomials = [
+            m([1, 2, 0]),
+            m([0, 3, 4]),
+            m([2, 0, 2]),
+            m([1, 2, 1]),
+            m([0, 0, 1]),
+        ];
+        for a in &monomials {
+            for b in &monomials {
+                for shift in &monomials {
+                    let (x, y) = (a.clone() * shift.clone(), b.clone() * shift.clone());
+                    assert_eq!(Lex::compare(a, b), Lex::compare(&x, &y));
+                    assert_eq!(GrLex::compare(a, b), GrLex::compare(&x, &y));
+                    assert_eq!(GrevLex::compare(a, b), GrevLex::compare(&x, &y));
+                }
+            }
+        }
+    }
+
+    // The divisions of §2.3, Examples 1 and 2, in lex order with x > y.
+
+    #[test]
+    fn division_by_several_polynomials() {
+        let f = poly::<Lex>(&[(1, [1, 2, 0]), (1, [0, 0, 0])]);
+        let divisors = [
+            poly(&[(1, [1, 1, 0]), (1, [0, 0, 0])]),
+            poly(&[(1, [0, 1, 0]), (1, [0, 0, 0])]),
+        ];
+        let (quotients, remainder) = f.div_rem(&divisors);
+        assert_eq!(
+            quotients,
+            [poly(&[(1, [0, 1, 0])]), poly(&[(-1, [0, 0, 0])])]
+        );
+        assert_eq!(remainder, poly(&[(2, [0, 0, 0])]));
+
+        let f = poly::<Lex>(&[(1, [2, 1, 0]), (1, [1, 2, 0]), (1, [0, 2, 0])]);
+        let divisors = [
+            poly(&[(1, [1, 1, 0]), (-1, [0, 0, 0])]),
+            poly(&[(1, [0, 2, 0]), (-1, [0, 0, 0])]),
+        ];
+        let (quotients, remainder) = f.div_rem(&divisors);
+        assert_eq!(
+            quotients,
+            [
+                poly(&[(1, [1, 0, 0]), (1, [0, 1, 0])]),
+                poly(&[(1, [0, 0, 0])])
+            ]
+        );
+        assert_eq!(
+            remainder,
+            poly(&[(1, [1, 0, 0]), (1, [0, 1, 0]), (1, [0, 0, 0])])
+        );
+        let recombined = quotients
+            .iter()
+            .zip(&divisors)
+            .fold(remainder, |sum, (q, g)| sum + q.clone() * g.clone());
+        assert_eq!(recombined, f);
+    }
+
+    #[test]
+    fn evaluation_and_derivatives() {
+        // f = x²y + 3yz - 2
+        let f = poly::<GrevLex>(&[(1, [2, 1, 0]), (3, [0, 1, 1]), (-2, [0, 0, 0])]);
+        assert_eq!(f.total_degree(), 3);
+        assert_eq!(f.variable_count(), 3);
+        assert_eq!(f.evaluate(&[c(2), c(5), c(7)]), c(20 + 105 - 2));
+        assert_eq!(f.partial_derivative(0), poly(&[(2, [1, 1, 0])]));
+        assert_eq!(
+            f.partial_derivative(1),
+            poly(&[(1, [2, 0, 0]), (3, [0, 0, 1])])
+        );
+        assert!(f.partial_derivative(3).is_zero());
+    }
+
+    #[test]
+    fn polynomials_over_a_field_form_an_integral_domain() {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        let gen = move || {
+            let terms = next() % 4;
+            MPoly::<F, GrLex>::new((0..terms).map(|_| {
+                let e = [next() % 3, next() % 3, next() % 2].map(|e| e as usize);
+                (m(e), F::new(next()))
+            }))
+        };
+        check_integral_domain(gen, 200).unwrap();
+    }
+}
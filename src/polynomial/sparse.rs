@@ -0,0 +1,497 @@
+//! Univariate polynomials stored as a map from degrees to non-zero coefficients.
+
+use super::{multiple, pow, write_terms, DensePoly};
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    Distributive, EuclideanValuation, ExactDivision, Field, NoZeroDivisors, Polynomial, Ring,
+    VectorSpace,
+};
+use num_traits::{Euclid, One, Zero};
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Represents a univariate polynomial over a ring R, storing only its non-zero terms.
+///
+/// # Mathematical Definition
+/// This is the polynomial ring R\[x\] of [`DensePoly`], with a polynomial held as the map
+/// k ↦ aₖ over the degrees k with aₖ ≠ 0. The cost of each operation depends on the number of
+/// terms rather than on the degree.
+///
+/// # Properties
+/// - R\[x\] is a commutative ring whenever R is, and an integral domain whenever R is
+/// - Over a field F, F\[x\] is a Euclidean domain with the degree as its Euclidean function
+/// - Dividing by a divisor with t terms costs O(t) per term of the quotient, so reducing modulo
+///   a vanishing polynomial xⁿ - 1 is linear in the size of the dividend
+///
+/// The zero polynomial has no terms, and reports degree 0 through [`Polynomial::degree`].
+///
+/// # Example
+/// ```
+/// use noether::{DensePoly, Fp, Polynomial, SparsePoly};
+/// use num_traits::One;
+///
+/// type F = Fp<65537>;
+///
+/// // The vanishing polynomial of the 65536th roots of unity.
+/// let z = SparsePoly::monomial(F::one(), 65536) - SparsePoly::one();
+/// let p = SparsePoly::new([(0, F::new(5)), (70000, F::new(2))]);
+///
+/// // x⁷⁰⁰⁰⁰ = x⁴⁴⁶⁴ · x⁶⁵⁵³⁶ ≡ x⁴⁴⁶⁴ modulo x⁶⁵⁵³⁶ - 1.
+/// let (q, r) = p.div_rem(&z);
+/// assert_eq!(q, SparsePoly::monomial(F::new(2), 4464));
+/// assert_eq!(r, SparsePoly::new([(0, F::new(5)), (4464, F::new(2))]));
+/// assert_eq!(z.degree(), 65536);
+/// assert_eq!(z.evaluate(&F::new(3)), F::new(0));
+///
+/// let dense = DensePoly::from(r.clone());
+/// assert_eq!(SparsePoly::from(dense), r);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SparsePoly<R> {
+    terms: BTreeMap<usize, R>,
+}
+
+impl<R: Ring> SparsePoly<R> {
+    /// Creates the polynomial with the given `(degree, coefficient)` terms.
+    ///
+    /// Terms of equal degree are added together, and zero coefficients are dropped.
+    pub fn new(terms: impl IntoIterator<Item = (usize, R)>) -> Self {
+        let mut polynomial = Self::zero();
+        for (degree, coefficient) in terms {
+            polynomial.add_term(degree, coefficient);
+        }
+        polynomial
+    }
+
+    /// Creates the monomial `coefficient · xᵈᵉᵍʳᵉᵉ`.
+    pub fn monomial(coefficient: R, degree: usize) -> Self {
+        Self::new([(degree, coefficient)])
+    }
+
+    /// Creates the indeterminate x.
+    pub fn x() -> Self {
+        Self::monomial(R::one(), 1)
+    }
+
+    /// Returns the non-zero terms as `(degree, coefficient)` pairs, lowest degree first.
+    pub fn terms(&self) -> impl DoubleEndedIterator<Item = (usize, &R)> + '_ {
+        self.terms.iter().map(|(&degree, c)| (degree, c))
+    }
+
+    /// Returns the number of non-zero terms.
+    pub fn term_count(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// Returns the coefficient of the highest power of x, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<&R> {
+        self.terms.values().next_back()
+    }
+
+    /// Evaluates the polynomial at `x`.
+    ///
+    /// This is Horner's method over the gaps between consecutive terms, raising `x` to each gap
+    /// by repeated squaring.
+    pub fn evaluate(&self, x: &R) -> R {
+        let mut terms = self.terms.iter().rev();
+        let Some((&degree, c)) = terms.next() else {
+            return R::zero();
+        };
+        let (mut acc, mut previous) = (c.clone(), degree);
+        for (&degree, c) in terms {
+            acc = acc * pow(x, previous - degree) + c.clone();
+            previous = degree;
+        }
+        acc * pow(x, previous)
+    }
+
+    /// Returns the formal derivative, the sum of k·aₖxᵏ⁻¹ over the terms.
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.terms
+                .iter()
+                .filter(|(&degree, _)| degree > 0)
+                .map(|(&degree, c)| (degree - 1, multiple(c, degree))),
+        )
+    }
+
+    /// Returns the polynomial with every coefficient multiplied by `scalar`.
+    pub fn scale_by(&self, scalar: &R) -> Self {
+        Self::new(
+            self.terms
+                .iter()
+                .map(|(&degree, c)| (degree, c.clone() * scalar.clone())),
+        )
+    }
+
+    /// Adds `coefficient · xᵈᵉᵍʳᵉᵉ`, removing the term if it cancels.
+    fn add_term(&mut self, degree: usize, coefficient: R) {
+        match self.terms.entry(degree) {
+            Entry::Vacant(entry) => {
+                if !coefficient.is_zero() {
+                    entry.insert(coefficient);
+                }
+            }
+            Entry::Occupied(mut entry) => {
+                *entry.get_mut() += coefficient;
+                if entry.get().is_zero() {
+                    entry.remove();
+                }
+            }
+        }
+    }
+}
+
+impl<F: Field> SparsePoly<F> {
+    /// Returns the quotient and remainder of the division of `self` by `divisor`.
+    ///
+    /// The remainder is zero or has a smaller degree than the divisor. Each step cancels the
+    /// highest term of the remainder and only touches the terms of the divisor, so sparse
+    /// divisors are cheap regardless of their degree.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let Some((&n, leading)) = divisor.terms.last_key_value() else {
+            panic!("attempt to divide by zero");
+        };
+        let leading_inv = leading.clone().inv();
+        let mut remainder = self.clone();
+        let mut quotient = Self::zero();
+        while let Some((&degree, c)) = remainder.terms.last_key_value() {
+            if degree < n {
+                break;
+            }
+            let shift = degree - n;
+            let factor = c.clone() * leading_inv.clone();
+            // The leading term cancels by construction.
+            remainder.terms.remove(&degree);
+            for (&d, b) in divisor.terms.range(..n) {
+                remainder.add_term(d + shift, -(factor.clone() * b.clone()));
+            }
+            quotient.terms.insert(shift, factor);
+        }
+        (quotient, remainder)
+    }
+
+    /// Returns the polynomial divided by its leading coefficient, or zero for zero.
+    pub fn monic(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(leading) => self.scale_by(&leading.clone().inv()),
+            None => Self::zero(),
+        }
+    }
+}
+
+impl<R: Ring> From<R> for SparsePoly<R> {
+    /// Embeds `value` as a constant polynomial.
+    fn from(value: R) -> Self {
+        Self::new([(0, value)])
+    }
+}
+
+impl<R: Ring> From<DensePoly<R>> for SparsePoly<R> {
+    fn from(polynomial: DensePoly<R>) -> Self {
+        Self::new(polynomial.coefficients().iter().cloned().enumerate())
+    }
+}
+
+impl<R: Ring> From<SparsePoly<R>> for DensePoly<R> {
+    fn from(polynomial: SparsePoly<R>) -> Self {
+        let Some((&degree, _)) = polynomial.terms.last_key_value() else {
+            return DensePoly::zero();
+        };
+        let mut coefficients = vec![R::zero(); degree + 1];
+        for (degree, c) in polynomial.terms {
+            coefficients[degree] = c;
+        }
+        DensePoly::new(coefficients)
+    }
+}
+
+impl<R: Ring + fmt::Display> fmt::Display for SparsePoly<R> {
+    /// Formats the polynomial from the highest degree down, as in `2x^2 + x + 5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(f, self.terms().rev())
+    }
+}
+
+impl<R: Ring> Add for SparsePoly<R> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: Ring> AddAssign for SparsePoly<R> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (degree, c) in rhs.terms {
+            self.add_term(degree, c);
+        }
+    }
+}
+
+impl<R: Ring> Neg for SparsePoly<R> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        SparsePoly {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|(degree, c)| (degree, -c))
+                .collect(),
+        }
+    }
+}
+
+impl<R: Ring> Sub for SparsePoly<R> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl<R: Ring> SubAssign for SparsePoly<R> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self += -rhs;
+    }
+}
+
+impl<R: Ring> Mul for SparsePoly<R> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut product = Self::zero();
+        for (i, a) in &self.terms {
+            for (j, b) in &rhs.terms {
+                product.add_term(i + j, a.clone() * b.clone());
+            }
+        }
+        product
+    }
+}
+
+impl<R: Ring> MulAssign for SparsePoly<R> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::replace(self, Self::zero()) * rhs;
+    }
+}
+
+impl<R: Ring> Zero for SparsePoly<R> {
+    fn zero() -> Self {
+        SparsePoly {
+            terms: BTreeMap::new(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<R: Ring> One for SparsePoly<R> {
+    fn one() -> Self {
+        Self::from(R::one())
+    }
+}
+
+impl<F: Field> Div for SparsePoly<F> {
+    type Output = Self;
+
+    /// Returns the quotient of polynomial long division.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<F: Field> DivAssign for SparsePoly<F> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).0;
+    }
+}
+
+impl<F: Field> Rem for SparsePoly<F> {
+    type Output = Self;
+
+    /// Returns the remainder of polynomial long division.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<F: Field> RemAssign for SparsePoly<F> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).1;
+    }
+}
+
+impl<F: Field> Euclid for SparsePoly<F> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).0
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        self.div_rem(v).1
+    }
+}
+
+impl<F: Field> EuclideanValuation for SparsePoly<F> {
+    /// Returns the degree, which Euclidean division lowers.
+    fn euclidean_size(&self) -> u128 {
+        self.degree() as u128
+    }
+}
+
+impl<F: Field> Polynomial for SparsePoly<F> {
+    type Coefficient = F;
+
+    fn degree(&self) -> usize {
+        self.terms.keys().next_back().copied().unwrap_or(0)
+    }
+
+    fn coefficient(&self, degree: usize) -> F {
+        self.terms.get(&degree).cloned().unwrap_or_else(F::zero)
+    }
+}
+
+impl<F: Field> VectorSpace for SparsePoly<F> {
+    type Scalar = F;
+
+    fn scale(&self, scalar: &F) -> Self {
+        self.scale_by(scalar)
+    }
+
+    /// Returns `None`, since F\[x\] has the infinite basis 1, x, x², ...
+    fn dimension(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl<R: CommutativeAddition> CommutativeAddition for SparsePoly<R> {}
+impl<R: AssociativeAddition> AssociativeAddition for SparsePoly<R> {
+    const BOUNDED: bool = R::BOUNDED;
+}
+impl<R: CommutativeMultiplication> CommutativeMultiplication for SparsePoly<R> {}
+impl<R: AssociativeMultiplication> AssociativeMultiplication for SparsePoly<R> {}
+impl<R: Distributive> Distributive for SparsePoly<R> {}
+impl<R: NoZeroDivisors> NoZeroDivisors for SparsePoly<R> {}
+
+/// Divides every coefficient.
+impl<R: ExactDivision> ExactDivision for SparsePoly<R> {
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        let terms = self
+            .terms()
+            .map(|(degree, c)| Some((degree, c.div_exact(n)?)));
+        terms.collect::<Option<Vec<_>>>().map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Fp;
+
+    type F = Fp<65537>;
+
+    fn dense(p: SparsePoly<F>) -> DensePoly<F> {
+        DensePoly::from(p)
+    }
+
+    /// Returns a generator of sparse polynomials with up to `max_terms` terms of degree below
+    /// `max_degree`.
+    fn polynomials(max_terms: u64, max_degree: u64, seed: u64) -> impl FnMut() -> SparsePoly<F> {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        move || {
+            let terms = next() % (max_terms + 1);
+            SparsePoly::new(
+                (0..terms).map(|_| ((next() % max_degree) as usize, F::new(next() % 7))),
+            )
+        }
+    }
+
+    #[test]
+    fn conversions_to_and_from_dense_round_trip() {
+        let mut gen = polynomials(8, 50, 1);
+        for _ in 0..100 {
+            let sparse = gen();
+            let dense = dense(sparse.clone());
+            assert_eq!(SparsePoly::from(dense.clone()), sparse);
+            assert_eq!(dense.degree(), sparse.degree());
+            for k in 0..55 {
+                assert_eq!(dense.coefficient(k), sparse.coefficient(k));
+            }
+        }
+        assert!(dense(SparsePoly::<F>::zero()).is_zero());
+    }
+
+    #[test]
+    fn arithmetic_agrees_with_dense_polynomials() {
+        let mut gen = polynomials(6, 30, 2);
+        for _ in 0..100 {
+            let (a, b) = (gen(), gen());
+            let (x, y) = (dense(a.clone()), dense(b.clone()));
+            assert_eq!(dense(a.clone() + b.clone()), x.clone() + y.clone());
+            assert_eq!(dense(a.clone() - b.clone()), x.clone() - y.clone());
+            assert_eq!(dense(a.clone() * b.clone()), x.clone() * y.clone());
+            assert_eq!(dense(a.derivative()), x.derivative());
+            assert_eq!(a.evaluate(&F::new(3)), x.evaluate(&F::new(3)));
+        }
+    }
+
+    #[test]
+    fn division_agrees_with_dense_division() {
+        let mut a = polynomials(10, 80, 3);
+        let mut b = polynomials(4, 20, 4);
+        for _ in 0..200 {
+            let (a, b) = (a(), b());
+            if b.is_zero() {
+                continue;
+            }
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(q.clone() * b.clone() + r.clone(), a);
+            assert!(r.is_zero() || r.degree() < b.degree());
+            let (dense_q, dense_r) = dense(a).div_rem(&dense(b));
+            assert_eq!(dense(q), dense_q);
+            assert_eq!(dense(r), dense_r);
+        }
+    }
+
+    #[test]
+    fn reducing_modulo_a_vanishing_polynomial_folds_the_exponents() {
+        // xⁿ ≡ x^(n mod 1024) modulo x¹⁰²⁴ - 1.
+        let z = SparsePoly::monomial(F::one(), 1024) - SparsePoly::one();
+        let p = SparsePoly::new([(5000, F::new(3)), (1030, F::new(2)), (7, F::one())]);
+        let r = p % z;
+        assert_eq!(
+            r,
+            SparsePoly::new([(904, F::new(3)), (6, F::new(2)), (7, F::one())])
+        );
+        assert_eq!(r.term_count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn dividing_by_zero_panics() {
+        let _ = SparsePoly::x() / SparsePoly::<F>::zero();
+    }
+}
@@ -0,0 +1,915 @@
+//! Prime fields with multi-limb moduli, in Montgomery form.
+//!
+//! Elements are stored as `L` little-endian 64-bit limbs holding a·R mod p, where R = 2⁶⁴ᴸ.
+//! Multiplication uses the CIOS (coarsely integrated operand scanning) form of Montgomery
+//! multiplication, which avoids any division by p. Addition, subtraction, negation, equality and
+//! the zero test run in constant time; inversion runs in time depending only on the modulus.
+
+use crate::zn::inverse_mod;
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    ConditionallySelectable, Distributive, EuclideanValuation, ExactDivision, FiniteField,
+    NoZeroDivisors, TwoAdicField, UInt,
+};
+use num_traits::{Euclid, Inv, One, Zero};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// The parameters of a [`FinitePrimeField`] with `L` limbs.
+///
+/// Only the modulus needs to be given; the Montgomery constants are computed from it at compile
+/// time. The modulus must be an odd prime, which is the implementor's responsibility: an even
+/// modulus fails to compile, but primality is not checked.
+///
+/// # Example
+/// ```
+/// use noether::{FinitePrimeField, PrimeFieldParams};
+/// use num_traits::{Inv, One};
+///
+/// /// The base field of the BN254 curve.
+/// struct Bn254Fq;
+///
+/// impl PrimeFieldParams<4> for Bn254Fq {
+///     const MODULUS: [u64; 4] = [
+///         0x3C208C16D87CFD47,
+///         0x97816A916871CA8D,
+///         0xB85045B68181585D,
+///         0x30644E72E131A029,
+///     ];
+/// }
+///
+/// type Fq = FinitePrimeField<Bn254Fq, 4>;
+///
+/// let a = Fq::from(3);
+/// assert_eq!(a * a.inv(), Fq::one());
+/// assert_eq!(-a + a, Fq::from(0));
+/// ```
+pub trait PrimeFieldParams<const L: usize> {
+    /// The prime modulus p, as little-endian limbs.
+    const MODULUS: [u64; L];
+
+    /// R mod p, the Montgomery form of one.
+    const R: [u64; L] = pow2_mod(&Self::MODULUS, 64 * L);
+
+    /// R² mod p, used to convert into Montgomery form.
+    const R2: [u64; L] = pow2_mod(&Self::MODULUS, 128 * L);
+
+    /// -p⁻¹ mod 2⁶⁴, used by Montgomery reduction.
+    const INV: u64 = neg_inv(Self::MODULUS[0]);
+}
+
+/// An element of the prime field 𝔽ₚ, where p is given by `P` and fits in `L` 64-bit limbs.
+///
+/// # Mathematical Definition
+/// 𝔽ₚ is the set {0, 1, ..., p - 1} with addition and multiplication modulo the prime p.
+///
+/// # Properties
+/// - 𝔽ₚ is a field of characteristic p with p elements
+/// - Every nonzero element a satisfies aᵖ⁻¹ = 1 (Fermat's little theorem), so a⁻¹ = aᵖ⁻²
+///
+/// Values are reduced on construction, so [`FinitePrimeField::from_limbs`] accepts any `L`-limb
+/// integer.
+pub struct FinitePrimeField<P, const L: usize> {
+    /// The Montgomery form a·R mod p of the element a.
+    limbs: [u64; L],
+    params: PhantomData<fn() -> P>,
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> FinitePrimeField<P, L> {
+    /// The additive identity.
+    pub const ZERO: Self = Self::from_montgomery([0; L]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self::from_montgomery(P::R);
+
+    const fn from_montgomery(limbs: [u64; L]) -> Self {
+        FinitePrimeField {
+            limbs,
+            params: PhantomData,
+        }
+    }
+
+    /// Creates the residue class of the little-endian integer `limbs` modulo p.
+    pub fn from_limbs(limbs: [u64; L]) -> Self {
+        // limbs · R² · R⁻¹ = limbs · R, and limbs · R² < R · p, so a single reduction suffices.
+        Self::from_montgomery(mont_mul(&limbs, &P::R2, &P::MODULUS, P::INV))
+    }
+
+    /// Returns the canonical representative of the element, in `0..p`, as little-endian limbs.
+    pub fn to_limbs(&self) -> [u64; L] {
+        let mut one = [0; L];
+        one[0] = 1;
+        mont_mul(&self.limbs, &one, &P::MODULUS, P::INV)
+    }
+
+    /// Returns the modulus p as little-endian limbs.
+    pub const fn modulus() -> [u64; L] {
+        P::MODULUS
+    }
+}
+
+impl<P, const L: usize> Clone for FinitePrimeField<P, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P, const L: usize> Copy for FinitePrimeField<P, L> {}
+
+impl<P, const L: usize> PartialEq for FinitePrimeField<P, L> {
+    fn eq(&self, other: &Self) -> bool {
+        // Both sides are fully reduced, so equal elements have equal limbs.
+        self.limbs
+            .iter()
+            .zip(&other.limbs)
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+    }
+}
+
+impl<P, const L: usize> Eq for FinitePrimeField<P, L> {}
+
+impl<P, const L: usize> Hash for FinitePrimeField<P, L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.limbs.hash(state);
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> fmt::Debug for FinitePrimeField<P, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FinitePrimeField({self})")
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> fmt::Display for FinitePrimeField<P, L> {
+    /// Formats the canonical representative in hexadecimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        for limb in self.to_limbs().iter().rev() {
+            write!(f, "{limb:016x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> From<u64> for FinitePrimeField<P, L> {
+    fn from(value: u64) -> Self {
+        let mut limbs = [0; L];
+        limbs[0] = value;
+        Self::from_limbs(limbs)
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Add for FinitePrimeField<P, L> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_montgomery(add_mod(&self.limbs, &rhs.limbs, &P::MODULUS))
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Sub for FinitePrimeField<P, L> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_montgomery(sub_mod(&self.limbs, &rhs.limbs, &P::MODULUS))
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Neg for FinitePrimeField<P, L> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Mul for FinitePrimeField<P, L> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_montgomery(mont_mul(&self.limbs, &rhs.limbs, &P::MODULUS, P::INV))
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Inv for FinitePrimeField<P, L> {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse a⁻¹ = aᵖ⁻².
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    fn inv(self) -> Self {
+        assert!(
+            !self.is_zero(),
+            "attempt to invert zero in FinitePrimeField"
+        );
+        let exponent = sub_small(&P::MODULUS, 2);
+        Self::from_montgomery(mont_pow(&self.limbs, &exponent, &P::R, &P::MODULUS, P::INV))
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> ExactDivision for FinitePrimeField<P, L> {
+    /// Multiplies by the inverse of n, which exists unless p divides n.
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        small_inverse(n as u64, &P::MODULUS).map(|inverse| *self * Self::from_limbs(inverse))
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> ConditionallySelectable for FinitePrimeField<P, L> {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self::from_montgomery(select(&a.limbs, &b.limbs, choice))
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Div for FinitePrimeField<P, L> {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Rem for FinitePrimeField<P, L> {
+    type Output = Self;
+
+    /// Returns the remainder of field division, which is always zero.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            !rhs.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        Self::ZERO
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> AddAssign for FinitePrimeField<P, L> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> SubAssign for FinitePrimeField<P, L> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> MulAssign for FinitePrimeField<P, L> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> DivAssign for FinitePrimeField<P, L> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> RemAssign for FinitePrimeField<P, L> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Zero for FinitePrimeField<P, L> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.limbs.iter().fold(0, |acc, limb| acc | limb) == 0
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> One for FinitePrimeField<P, L> {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> Euclid for FinitePrimeField<P, L> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> EuclideanValuation for FinitePrimeField<P, L> {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(!self.is_zero())
+    }
+}
+
+impl<P, const L: usize> CommutativeAddition for FinitePrimeField<P, L> {}
+impl<P, const L: usize> AssociativeAddition for FinitePrimeField<P, L> {}
+impl<P, const L: usize> CommutativeMultiplication for FinitePrimeField<P, L> {}
+impl<P, const L: usize> AssociativeMultiplication for FinitePrimeField<P, L> {}
+impl<P, const L: usize> Distributive for FinitePrimeField<P, L> {}
+impl<P, const L: usize> NoZeroDivisors for FinitePrimeField<P, L> {}
+
+impl<P: PrimeFieldParams<L>, const L: usize> FiniteField for FinitePrimeField<P, L> {
+    type ScalarType = UInt<L>;
+
+    fn characteristic() -> UInt<L> {
+        UInt::from_limbs(P::MODULUS)
+    }
+
+    fn order() -> UInt<L> {
+        UInt::from_limbs(P::MODULUS)
+    }
+
+    fn extension_degree() -> u32 {
+        1
+    }
+
+    /// Returns `self`, since aᵖ = a for every a in 𝔽ₚ.
+    fn absolute_frobenius_map(&self, _power: usize) -> Self {
+        *self
+    }
+}
+
+impl<P: PrimeFieldParams<L>, const L: usize> TwoAdicField for FinitePrimeField<P, L> {
+    const TWO_ADICITY: u32 = trailing_zeros(&sub_small(&P::MODULUS, 1));
+
+    /// Returns g^((p - 1) / 2ˢ) for the smallest quadratic non-residue g, found by Euler's
+    /// criterion g^((p - 1) / 2) = -1.
+    fn two_adic_root_of_unity() -> Self {
+        let minus_one = sub_small(&P::MODULUS, 1);
+        let half = shr(&minus_one, 1);
+        let pow = |base: &Self, exponent: &[u64; L]| {
+            Self::from_montgomery(mont_pow(&base.limbs, exponent, &P::R, &P::MODULUS, P::INV))
+        };
+        let non_residue = (2..)
+            .map(Self::from)
+            .find(|g| pow(g, &half) == -Self::ONE)
+            .unwrap();
+        pow(&non_residue, &shr(&minus_one, Self::TWO_ADICITY))
+    }
+}
+
+// Limb arithmetic
+//
+// The helpers below work on little-endian limbs and are shared with the runtime-modulus field.
+
+/// Returns a + b + carry as (sum, carry).
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Returns a - b - borrow as (difference, borrow), where borrow is 0 or 1.
+#[inline(always)]
+const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (t as u64, (t >> 127) as u64)
+}
+
+/// Returns a + b · c + carry as (low, high), which cannot overflow.
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Returns `a` if `choice` is 0 and `b` if it is 1, without branching.
+#[inline(always)]
+pub(crate) fn select<const L: usize>(a: &[u64; L], b: &[u64; L], choice: u64) -> [u64; L] {
+    let mask = 0u64.wrapping_sub(choice);
+    let mut result = [0; L];
+    for i in 0..L {
+        result[i] = (a[i] & !mask) | (b[i] & mask);
+    }
+    result
+}
+
+/// Returns a - b as (difference, borrow).
+const fn sub_limbs<const L: usize>(a: &[u64; L], b: &[u64; L]) -> ([u64; L], u64) {
+    let mut result = [0; L];
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < L {
+        (result[i], borrow) = sbb(a[i], b[i], borrow);
+        i += 1;
+    }
+    (result, borrow)
+}
+
+/// Returns a - b for a small b, assuming no underflow.
+pub(crate) const fn sub_small<const L: usize>(a: &[u64; L], b: u64) -> [u64; L] {
+    let mut result = *a;
+    let mut borrow = b;
+    let mut i = 0;
+    while i < L && borrow != 0 {
+        (result[i], borrow) = sbb(result[i], borrow, 0);
+        i += 1;
+    }
+    result
+}
+
+/// Returns the number of trailing zero bits of a nonzero integer.
+pub(crate) const fn trailing_zeros<const L: usize>(limbs: &[u64; L]) -> u32 {
+    let mut i = 0;
+    while limbs[i] == 0 {
+        i += 1;
+    }
+    i as u32 * 64 + limbs[i].trailing_zeros()
+}
+
+/// Returns `limbs >> shift` for a shift below 64 · L.
+pub(crate) fn shr<const L: usize>(limbs: &[u64; L], shift: u32) -> [u64; L] {
+    let (words, bits) = ((shift / 64) as usize, shift % 64);
+    let mut result = [0; L];
+    for i in 0..L - words {
+        result[i] = limbs[i + words] >> bits;
+        if bits > 0 && i + words + 1 < L {
+            result[i] |= limbs[i + words + 1] << (64 - bits);
+        }
+    }
+    result
+}
+
+/// Returns the inverse of `n` modulo the prime p, or `None` if p divides n.
+///
+/// The inverse is (kp + 1) / n for the k in `0..n` with kp ≡ -1 (mod n), which only takes a few
+/// passes over the limbs instead of an exponentiation.
+pub(crate) fn small_inverse<const L: usize>(n: u64, modulus: &[u64; L]) -> Option<[u64; L]> {
+    if n == 0 {
+        return None;
+    }
+    let remainder = modulus
+        .iter()
+        .rev()
+        .fold(0u128, |r, &limb| ((r << 64) | limb as u128) % n as u128) as u64;
+    let k = match n {
+        1 => 0,
+        _ => n - inverse_mod(remainder, n)?,
+    };
+    // kp + 1, whose carry-out limb is below n.
+    let mut value = [0; L];
+    let mut carry = 1u128;
+    for (limb, &p) in value.iter_mut().zip(modulus) {
+        let t = p as u128 * k as u128 + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+    for limb in value.iter_mut().rev() {
+        let t = (carry << 64) | *limb as u128;
+        *limb = (t / n as u128) as u64;
+        carry = t % n as u128;
+    }
+    Some(value)
+}
+
+/// Reduces a value below 2p, given with its carry-out limb, to the range `0..p`.
+#[inline(always)]
+fn reduce_once<const L: usize>(value: &[u64; L], carry: u64, modulus: &[u64; L]) -> [u64; L] {
+    let (reduced, borrow) = sub_limbs(value, modulus);
+    // The subtraction is kept when it does not underflow past the carry-out limb.
+    select(value, &reduced, carry | (borrow ^ 1))
+}
+
+/// Returns (a + b) mod p for a, b < p.
+pub(crate) fn add_mod<const L: usize>(a: &[u64; L], b: &[u64; L], modulus: &[u64; L]) -> [u64; L] {
+    let mut sum = [0; L];
+    let mut carry = 0;
+    for i in 0..L {
+        (sum[i], carry) = adc(a[i], b[i], carry);
+    }
+    reduce_once(&sum, carry, modulus)
+}
+
+/// Returns (a - b) mod p for a, b < p.
+pub(crate) fn sub_mod<const L: usize>(a: &[u64; L], b: &[u64; L], modulus: &[u64; L]) -> [u64; L] {
+    let (difference, borrow) = sub_limbs(a, b);
+    let masked = select(&[0; L], modulus, borrow);
+    let mut result = [0; L];
+    let mut carry = 0;
+    for i in 0..L {
+        (result[i], carry) = adc(difference[i], masked[i], carry);
+    }
+    result
+}
+
+/// Returns a · b · R⁻¹ mod p, for a · b < R · p.
+pub(crate) fn mont_mul<const L: usize>(
+    a: &[u64; L],
+    b: &[u64; L],
+    modulus: &[u64; L],
+    inv: u64,
+) -> [u64; L] {
+    // t holds L limbs plus the two carry limbs t_hi and t_top.
+    let mut t = [0u64; L];
+    let mut t_hi = 0u64;
+    for &b_i in b {
+        // t += a · bᵢ
+        let mut carry = 0;
+        for j in 0..L {
+            (t[j], carry) = mac(t[j], a[j], b_i, carry);
+        }
+        let t_top;
+        (t_hi, t_top) = adc(t_hi, carry, 0);
+
+        // t = (t + m · p) / 2⁶⁴, where m makes the lowest limb vanish
+        let m = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], m, modulus[0], 0);
+        for j in 1..L {
+            (t[j - 1], carry) = mac(t[j], m, modulus[j], carry);
+        }
+        let top;
+        (t[L - 1], top) = adc(t_hi, carry, 0);
+        t_hi = t_top + top;
+    }
+    reduce_once(&t, t_hi, modulus)
+}
+
+/// Returns baseᵉ in Montgomery form, where `one` is the Montgomery form of one.
+///
+/// The running time depends on the exponent, which must therefore be public.
+pub(crate) fn mont_pow<const L: usize>(
+    base: &[u64; L],
+    exponent: &[u64; L],
+    one: &[u64; L],
+    modulus: &[u64; L],
+    inv: u64,
+) -> [u64; L] {
+    let mut result = *one;
+    for limb in exponent.iter().rev() {
+        for bit in (0..64).rev() {
+            result = mont_mul(&result, &result, modulus, inv);
+            if (limb >> bit) & 1 == 1 {
+                result = mont_mul(&result, base, modulus, inv);
+            }
+        }
+    }
+    result
+}
+
+/// Returns -p⁻¹ mod 2⁶⁴ for the lowest limb p₀ of an odd modulus.
+pub(crate) const fn neg_inv(p0: u64) -> u64 {
+    assert!(p0 & 1 == 1, "the modulus of a Montgomery field must be odd");
+    // Newton's iteration doubles the number of correct low bits each step: 1 → 2 → ... → 64.
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(p0.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// Returns 2ᵏ mod p by repeated doubling.
+pub(crate) const fn pow2_mod<const L: usize>(modulus: &[u64; L], k: usize) -> [u64; L] {
+    assert!(L > 0, "a Montgomery field needs at least one limb");
+    let mut result = [0u64; L];
+    result[0] = 1;
+    let mut i = 0;
+    while i < k {
+        // result = 2 · result mod p
+        let mut carry = 0;
+        let mut j = 0;
+        while j < L {
+            let next = result[j] >> 63;
+            result[j] = (result[j] << 1) | carry;
+            carry = next;
+            j += 1;
+        }
+        let (reduced, borrow) = sub_limbs(&result, modulus);
+        if carry == 1 || borrow == 0 {
+            result = reduced;
+        }
+        i += 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::check_field;
+    use crate::BatchInversion;
+
+    /// The base field of the BN254 curve, of 254 bits.
+    struct Bn254;
+
+    impl PrimeFieldParams<4> for Bn254 {
+        const MODULUS: [u64; 4] = [
+            0x3C208C16D87CFD47,
+            0x97816A916871CA8D,
+            0xB85045B68181585D,
+            0x30644E72E131A029,
+        ];
+    }
+
+    /// The base field of the secp256k1 curve, whose modulus 2²⁵⁶ - 2³² - 977 makes sums of two
+    /// elements carry out of the top limb.
+    struct Secp256k1;
+
+    impl PrimeFieldParams<4> for Secp256k1 {
+        const MODULUS: [u64; 4] = [
+            0xFFFFFFFEFFFFFC2F,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+            0xFFFFFFFFFFFFFFFF,
+        ];
+    }
+
+    type Fq = FinitePrimeField<Bn254, 4>;
+    type Fk = FinitePrimeField<Secp256k1, 4>;
+
+    const A: [u64; 4] = [0x1234567890ABCDEF; 4];
+    const B: [u64; 4] = [0xFEDCBA0987654321; 4];
+
+    /// Returns a generator of pseudo-random elements from random limbs, most of them above p.
+    fn elements<P: PrimeFieldParams<4>>() -> impl FnMut() -> FinitePrimeField<P, 4> {
+        let mut state = 0x2545F4914F6CDD1Du64;
+        move || {
+            let mut limbs = [0; 4];
+            for limb in &mut limbs {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                *limb = state;
+            }
+            FinitePrimeField::from_limbs(limbs)
+        }
+    }
+
+    #[test]
+    fn montgomery_constants() {
+        assert_eq!(
+            Bn254::R,
+            [
+                0xD35D438DC58F0D9D,
+                0x0A78EB28F5C70B3D,
+                0x666EA36F7879462C,
+                0x0E0A77C19A07DF2F
+            ]
+        );
+        assert_eq!(
+            Bn254::R2,
+            [
+                0xF32CFC5B538AFA89,
+                0xB5E71911D44501FB,
+                0x47AB1EFF0A417FF6,
+                0x06D89F71CAB8351F
+            ]
+        );
+        assert_eq!(Bn254::INV, 0x87D20782E4866389);
+
+        assert_eq!(Secp256k1::R, [0x00000001000003D1, 0, 0, 0]);
+        assert_eq!(Secp256k1::R2, [0x000007A2000E90A1, 1, 0, 0]);
+        assert_eq!(Secp256k1::INV, 0xD838091DD2253531);
+    }
+
+    #[test]
+    fn montgomery_multiplication() {
+        let p = Bn254::MODULUS;
+        let minus_one = sub_small(&p, 1);
+        assert_eq!(
+            mont_mul(&minus_one, &minus_one, &p, Bn254::INV),
+            [
+                0xED84884A014AFA37,
+                0xEB2022850278EDF8,
+                0xCF63E9CFB74492D9,
+                0x2E67157159E5C639
+            ]
+        );
+        let b = Fq::from_limbs(B).to_limbs();
+        assert_eq!(
+            mont_mul(&A, &b, &p, Bn254::INV),
+            [
+                0xCE2D658C19682043,
+                0x8EEE83A9FB5D5D78,
+                0x03B63104A596615E,
+                0x15AA952C2BD40DAC
+            ]
+        );
+        // a · R · R⁻¹ = a and R · R · R⁻¹ = R
+        assert_eq!(mont_mul(&A, &Bn254::R, &p, Bn254::INV), A);
+        assert_eq!(mont_mul(&Bn254::R, &Bn254::R, &p, Bn254::INV), Bn254::R);
+
+        let p = Secp256k1::MODULUS;
+        let minus_one = sub_small(&p, 1);
+        assert_eq!(
+            mont_mul(&minus_one, &minus_one, &p, Secp256k1::INV),
+            [
+                0xD838091D0868192A,
+                0xBCB223FEDC24A059,
+                0x9C46C2C295F2B761,
+                0xC9BD190515538399
+            ]
+        );
+        assert_eq!(
+            mont_mul(&A, &B, &p, Secp256k1::INV),
+            [
+                0x5BA720766846EADA,
+                0x9FFFCCD7276BA4CC,
+                0xDF80767E70E5E12C,
+                0x042C83C71FC8E298
+            ]
+        );
+    }
+
+    #[test]
+    fn modular_addition_and_subtraction() {
+        let p = Bn254::MODULUS;
+        let minus_one = sub_small(&p, 1);
+        assert_eq!(add_mod(&minus_one, &small(1), &p), [0; 4]);
+        assert_eq!(add_mod(&minus_one, &minus_one, &p), sub_small(&p, 2));
+        assert_eq!(sub_mod(&[0; 4], &small(1), &p), minus_one);
+        assert_eq!(sub_mod(&small(1), &minus_one, &p), small(2));
+        assert_eq!(sub_mod(&A, &A, &p), [0; 4]);
+
+        // The sums below exceed 2²⁵⁶ and carry out of the top limb.
+        let p = Secp256k1::MODULUS;
+        let minus_one = sub_small(&p, 1);
+        assert_eq!(add_mod(&minus_one, &small(1), &p), [0; 4]);
+        assert_eq!(add_mod(&minus_one, &minus_one, &p), sub_small(&p, 2));
+        assert_eq!(
+            add_mod(&A, &B, &p),
+            [
+                0x11111083181114E1,
+                0x1111108218111111,
+                0x1111108218111111,
+                0x1111108218111111
+            ]
+        );
+        assert_eq!(sub_mod(&[0; 4], &small(1), &p), minus_one);
+        assert_eq!(sub_mod(&B, &A, &p), [0xECA86390F6B97532; 4]);
+        assert_eq!(
+            sub_mod(&A, &B, &p),
+            [
+                0x13579C6E094686FD,
+                0x13579C6F09468ACD,
+                0x13579C6F09468ACD,
+                0x13579C6F09468ACD
+            ]
+        );
+    }
+
+    #[test]
+    fn conversions_reduce_modulo_p() {
+        assert_eq!(Fq::from_limbs(Bn254::MODULUS), Fq::ZERO);
+        assert_eq!(Fq::from_limbs(sub_small(&Bn254::MODULUS, 1)), -Fq::ONE);
+        // 2²⁵⁶ - 1 ≡ R - 1
+        assert_eq!(
+            Fq::from_limbs([u64::MAX; 4]).to_limbs(),
+            sub_small(&Bn254::R, 1)
+        );
+        assert_eq!(
+            Fk::from_limbs([u64::MAX; 4]).to_limbs(),
+            [0x00000001000003D0, 0, 0, 0]
+        );
+        assert_eq!(Fq::from_limbs(A).to_limbs(), A);
+        assert_eq!(Fq::from(7).to_limbs(), small(7));
+        assert_eq!(
+            Fq::ONE.to_string(),
+            "0x0000000000000000000000000000000000000000000000000000000000000001"
+        );
+    }
+
+    #[test]
+    fn field_operations() {
+        let a = Fq::from_limbs(A);
+        let b = Fq::from_limbs(B);
+        assert_eq!(
+            (a * b).to_limbs(),
+            [
+                0x8BBE78BEB66616AD,
+                0xF66711133B2F73D9,
+                0x8590100D302A0D68,
+                0x200EB66C4B654300
+            ]
+        );
+        assert_eq!(
+            (a + b).to_limbs(),
+            [
+                0xE46E540FDDA01EAD,
+                0x1B89FBAB0DD81C4E,
+                0x777FB3F1908A573D,
+                0x1F1B8843B218F040
+            ]
+        );
+        assert_eq!(
+            (b - a).to_limbs(),
+            [
+                0xFC26333594C58016,
+                0x8EA2B94B54F24AFD,
+                0x0B674CB6F0B413BC,
+                0x2B1729C571F2F48C
+            ]
+        );
+        assert_eq!(
+            (Fk::from_limbs(A) * Fk::from_limbs(B)).to_limbs(),
+            [
+                0x354652A4E4897181,
+                0xC6A865E9CD771060,
+                0x6A2A19B97D502825,
+                0x0DABCD892D293FEB
+            ]
+        );
+    }
+
+    #[test]
+    fn fermat_inversion() {
+        let a = Fq::from_limbs(A);
+        assert_eq!(
+            a.inv().to_limbs(),
+            [
+                0x0063360ADD994601,
+                0xAF8F32D16E1A2607,
+                0x555EA182A995CA84,
+                0x095D995FDF74F443
+            ]
+        );
+        assert_eq!(a * a.inv(), Fq::ONE);
+        assert_eq!((-Fq::ONE).inv(), -Fq::ONE);
+        // 2⁻¹ = (p + 1) / 2 = ⌊p / 2⌋ + 1
+        let mut half = shr(&Bn254::MODULUS, 1);
+        half[0] += 1;
+        assert_eq!(Fq::from(2).inv().to_limbs(), half);
+        assert_eq!(
+            Fk::from_limbs(A).inv().to_limbs(),
+            [
+                0x74DDBF3FE1A798DA,
+                0x6495F48C67A920AD,
+                0x8A39B867E4368D3F,
+                0x12F1627C2951BE2F
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to invert zero")]
+    fn inverting_zero_panics() {
+        let _ = Fq::ZERO.inv();
+    }
+
+    #[test]
+    fn field_laws() {
+        check_field(elements::<Bn254>(), 300).unwrap();
+        check_field(elements::<Secp256k1>(), 300).unwrap();
+    }
+
+    #[test]
+    fn exact_division_by_small_integers() {
+        let mut element = elements::<Bn254>();
+        for n in [1, 2, 3, 5, 6, 7, 97, 65536, u32::MAX] {
+            let a = element();
+            let inverse = Fq::from(n as u64).inv();
+            assert_eq!(a.div_exact(n), Some(a * inverse), "n = {n}");
+        }
+        assert_eq!(Fq::ONE.div_exact(0), None);
+    }
+
+    #[test]
+    fn two_adic_root_of_unity() {
+        // p - 1 = 2 · odd for both moduli, so the root of unity is -1.
+        assert_eq!(Fq::TWO_ADICITY, 1);
+        assert_eq!(Fq::two_adic_root_of_unity(), -Fq::ONE);
+        assert_eq!(Fk::TWO_ADICITY, 1);
+        assert_eq!(Fk::two_adic_root_of_unity(), -Fk::ONE);
+    }
+
+    #[test]
+    fn batch_inversion_agrees_with_inversion() {
+        let mut element = elements::<Bn254>();
+        let mut values: Vec<Fq> = (0..50).map(|_| element()).collect();
+        for i in [0, 20, 21, 49] {
+            values[i] = Fq::zero();
+        }
+        let expected: Vec<Fq> = values
+            .iter()
+            .map(|a| if a.is_zero() { Fq::zero() } else { a.inv() })
+            .collect();
+        Fq::batch_invert_skipping_zeros(&mut values);
+        assert_eq!(values, expected);
+
+        let mut element = elements::<Secp256k1>();
+        let mut values: Vec<Fk> = (0..50).map(|_| element()).collect();
+        let expected: Vec<Fk> = values.iter().map(|a| a.inv()).collect();
+        Fk::batch_invert(&mut values);
+        assert_eq!(values, expected);
+    }
+
+    /// Returns the 4-limb integer `value`.
+    fn small(value: u64) -> [u64; 4] {
+        [value, 0, 0, 0]
+    }
+}
@@ -0,0 +1,531 @@
+//! Marker and structure impls for the primitive numeric types.
+//!
+//! The primitive types only satisfy the algebraic laws under a suitable interpretation:
+//! - `Wrapping<uN>` and `Wrapping<iN>` are the ring Z/2ⁿ, and are commutative rings.
+//! - `iN` overflows, so it is only a Euclidean domain through [`Checked`], which panics instead
+//!   of leaving the set of integers the type stands for.
+//! - `f32` and `f64` approximate the real numbers. Rounding breaks associativity and
+//!   distributivity, so their impls are opt-in through the `approximate-floats` feature.
+
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    ConditionallySelectable, Distributive, EuclideanValuation, ExactDivision, NoZeroDivisors,
+};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Euclid,
+    One, Zero,
+};
+use std::num::Wrapping;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+macro_rules! marker_impls {
+    ($($t:ty),*) => {
+        marker_impls!(bounded = false; $($t),*);
+    };
+    (bounded = $bounded:expr; $($t:ty),*) => {
+        $(
+            impl CommutativeAddition for $t {}
+            impl AssociativeAddition for $t {
+                const BOUNDED: bool = $bounded;
+            }
+            impl CommutativeMultiplication for $t {}
+            impl AssociativeMultiplication for $t {}
+            impl Distributive for $t {}
+        )*
+    };
+}
+
+// Wrapping
+//
+// Arithmetic modulo 2ⁿ satisfies every ring law. Z/2ⁿ has zero divisors (2ⁿ⁻¹ · 2 = 0), so
+// these types do not implement `NoZeroDivisors` and stop at `CommutativeRing`.
+
+marker_impls!(
+    Wrapping<u8>,
+    Wrapping<u16>,
+    Wrapping<u32>,
+    Wrapping<u64>,
+    Wrapping<u128>,
+    Wrapping<usize>,
+    Wrapping<i8>,
+    Wrapping<i16>,
+    Wrapping<i32>,
+    Wrapping<i64>,
+    Wrapping<i128>,
+    Wrapping<isize>
+);
+
+// 2 is a zero divisor of Z/2ⁿ, so the Wrapping integers keep the default, which divides by
+// nothing.
+impl ExactDivision for Wrapping<u8> {}
+impl ExactDivision for Wrapping<u16> {}
+impl ExactDivision for Wrapping<u32> {}
+impl ExactDivision for Wrapping<u64> {}
+impl ExactDivision for Wrapping<u128> {}
+impl ExactDivision for Wrapping<usize> {}
+impl ExactDivision for Wrapping<i8> {}
+impl ExactDivision for Wrapping<i16> {}
+impl ExactDivision for Wrapping<i32> {}
+impl ExactDivision for Wrapping<i64> {}
+impl ExactDivision for Wrapping<i128> {}
+impl ExactDivision for Wrapping<isize> {}
+
+/// An integer whose arithmetic panics on overflow instead of wrapping or saturating.
+///
+/// Within the range of the underlying type every result is exact, so `Checked<iN>` satisfies
+/// the laws of the integers ℤ and is classified as a [`EuclideanDomain`](crate::EuclideanDomain).
+/// Operations whose exact result does not fit panic, in both debug and release builds.
+///
+/// `/` and `%` truncate towards zero like the primitive operators, while
+/// [`Euclid::div_euclid`] and [`Euclid::rem_euclid`] give the Euclidean quotient and the
+/// non-negative remainder.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checked<T>(pub T);
+
+impl<T> Checked<T> {
+    /// Returns the underlying integer.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Checked<T> {
+    fn from(value: T) -> Self {
+        Checked(value)
+    }
+}
+
+impl<T: CheckedAdd> Add for Checked<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Checked(
+            self.0
+                .checked_add(&rhs.0)
+                .expect("attempt to add with overflow"),
+        )
+    }
+}
+
+impl<T: CheckedSub> Sub for Checked<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Checked(
+            self.0
+                .checked_sub(&rhs.0)
+                .expect("attempt to subtract with overflow"),
+        )
+    }
+}
+
+impl<T: CheckedMul> Mul for Checked<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Checked(
+            self.0
+                .checked_mul(&rhs.0)
+                .expect("attempt to multiply with overflow"),
+        )
+    }
+}
+
+impl<T: CheckedDiv> Div for Checked<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Checked(
+            self.0
+                .checked_div(&rhs.0)
+                .expect("attempt to divide by zero or with overflow"),
+        )
+    }
+}
+
+impl<T: CheckedRem> Rem for Checked<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Checked(
+            self.0.checked_rem(&rhs.0).expect(
+                "attempt to calculate the remainder with a divisor of zero or with overflow",
+            ),
+        )
+    }
+}
+
+impl<T: CheckedNeg> Neg for Checked<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Checked(
+            self.0
+                .checked_neg()
+                .expect("attempt to negate with overflow"),
+        )
+    }
+}
+
+impl<T: CheckedAdd> AddAssign for Checked<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = Checked(
+            self.0
+                .checked_add(&rhs.0)
+                .expect("attempt to add with overflow"),
+        );
+    }
+}
+
+impl<T: CheckedSub> SubAssign for Checked<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = Checked(
+            self.0
+                .checked_sub(&rhs.0)
+                .expect("attempt to subtract with overflow"),
+        );
+    }
+}
+
+impl<T: CheckedMul> MulAssign for Checked<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = Checked(
+            self.0
+                .checked_mul(&rhs.0)
+                .expect("attempt to multiply with overflow"),
+        );
+    }
+}
+
+impl<T: CheckedDiv> DivAssign for Checked<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = Checked(
+            self.0
+                .checked_div(&rhs.0)
+                .expect("attempt to divide by zero or with overflow"),
+        );
+    }
+}
+
+impl<T: CheckedRem> RemAssign for Checked<T> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self =
+            Checked(self.0.checked_rem(&rhs.0).expect(
+                "attempt to calculate the remainder with a divisor of zero or with overflow",
+            ));
+    }
+}
+
+impl<T: Zero + CheckedAdd> Zero for Checked<T> {
+    fn zero() -> Self {
+        Checked(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One + CheckedMul> One for Checked<T> {
+    fn one() -> Self {
+        Checked(T::one())
+    }
+}
+
+impl<T: CheckedEuclid + CheckedDiv + CheckedRem> Euclid for Checked<T> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        Checked(
+            self.0
+                .checked_div_euclid(&v.0)
+                .expect("attempt to divide by zero or with overflow"),
+        )
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        Checked(
+            self.0.checked_rem_euclid(&v.0).expect(
+                "attempt to calculate the remainder with a divisor of zero or with overflow",
+            ),
+        )
+    }
+}
+
+macro_rules! checked_valuation_impls {
+    ($($t:ty),*) => {
+        $(
+            impl EuclideanValuation for Checked<$t> {
+                /// Returns the absolute value, which bounds the non-negative remainders.
+                fn euclidean_size(&self) -> u128 {
+                    self.0.unsigned_abs() as u128
+                }
+            }
+        )*
+    };
+}
+
+checked_valuation_impls!(i8, i16, i32, i64, i128, isize);
+
+// Only the signed integers are closed under negation, so only they form a ring. Their sums
+// panic outside the range of the type, so they are bounded.
+marker_impls!(
+    bounded = true;
+    Checked<i8>,
+    Checked<i16>,
+    Checked<i32>,
+    Checked<i64>,
+    Checked<i128>,
+    Checked<isize>
+);
+
+impl NoZeroDivisors for Checked<i8> {}
+impl NoZeroDivisors for Checked<i16> {}
+impl NoZeroDivisors for Checked<i32> {}
+impl NoZeroDivisors for Checked<i64> {}
+impl NoZeroDivisors for Checked<i128> {}
+impl NoZeroDivisors for Checked<isize> {}
+
+macro_rules! checked_exact_division_impls {
+    ($($t:ty),*) => {
+        $(
+            impl ExactDivision for Checked<$t> {
+                /// Returns the quotient when n divides `self`, which is the unique one in ℤ.
+                fn div_exact(&self, n: u32) -> Option<Self> {
+                    let n = <$t>::try_from(n).ok().filter(|&n| n != 0)?;
+                    (self.0 % n == 0).then(|| Checked(self.0 / n))
+                }
+            }
+        )*
+    };
+}
+
+checked_exact_division_impls!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! selectable_impls {
+    ($($t:ty),*) => {
+        $(
+            impl ConditionallySelectable for Checked<$t> {
+                fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+                    let mask = (choice as $t).wrapping_neg();
+                    Checked((a.0 & !mask) | (b.0 & mask))
+                }
+            }
+
+            impl ConditionallySelectable for Wrapping<$t> {
+                fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+                    let mask = (choice as $t).wrapping_neg();
+                    Wrapping((a.0 & !mask) | (b.0 & mask))
+                }
+            }
+        )*
+    };
+}
+
+selectable_impls!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Floating point
+//
+// IEEE 754 arithmetic rounds every result, so the field laws only hold approximately: for
+// example (0.1 + 0.2) + 0.3 != 0.1 + (0.2 + 0.3). Generic code which tolerates rounding error
+// may still treat the floats as real numbers, which the `approximate-floats` feature enables.
+
+#[cfg(feature = "approximate-floats")]
+marker_impls!(f32, f64);
+
+// Underflow makes products of tiny non-zero floats zero, which the approximation disregards.
+
+#[cfg(feature = "approximate-floats")]
+impl NoZeroDivisors for f32 {}
+
+#[cfg(feature = "approximate-floats")]
+impl NoZeroDivisors for f64 {}
+
+// Float division rounds, so the floats keep the default of ExactDivision, which divides by
+// nothing.
+
+#[cfg(feature = "approximate-floats")]
+impl ExactDivision for f32 {}
+
+#[cfg(feature = "approximate-floats")]
+impl ExactDivision for f64 {}
+
+/// Selects between the bit patterns.
+#[cfg(feature = "approximate-floats")]
+impl ConditionallySelectable for f32 {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        let mask = 0u32.wrapping_sub(choice as u32);
+        f32::from_bits((a.to_bits() & !mask) | (b.to_bits() & mask))
+    }
+}
+
+/// Selects between the bit patterns.
+#[cfg(feature = "approximate-floats")]
+impl ConditionallySelectable for f64 {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        let mask = 0u64.wrapping_sub(choice);
+        f64::from_bits((a.to_bits() & !mask) | (b.to_bits() & mask))
+    }
+}
+
+// As approximations of the field ℝ, the floats take the Euclidean function of a field: 0 at zero
+// and 1 elsewhere. `Euclid` for the floats is the floored division of `num-traits`, whose
+// remainders are not zero, so Euclidean algorithms over the floats are no more exact than the
+// other field laws.
+
+#[cfg(feature = "approximate-floats")]
+impl EuclideanValuation for f32 {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(*self != 0.0)
+    }
+}
+
+#[cfg(feature = "approximate-floats")]
+impl EuclideanValuation for f64 {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(*self != 0.0)
+    }
+}
+
+#[cfg(feature = "approximate-floats")]
+impl crate::RealField for f32 {}
+
+#[cfg(feature = "approximate-floats")]
+impl crate::RealField for f64 {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::{check_commutative_ring, check_euclidean_domain};
+
+    /// Returns a generator of pseudo-random values mapped through `f`.
+    fn sample<T>(f: impl Fn(u64) -> T) -> impl FnMut() -> T {
+        let mut state = 0x9E3779B97F4A7C15u64;
+        move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            f(state)
+        }
+    }
+
+    #[test]
+    fn checked_integers_are_a_euclidean_domain() {
+        check_euclidean_domain(sample(|x| Checked((x % 2001) as i64 - 1000)), 2000).unwrap();
+        assert_eq!(Euclid::div_euclid(&Checked(-7), &Checked(2)), Checked(-4));
+        assert_eq!(Euclid::rem_euclid(&Checked(-7), &Checked(2)), Checked(1));
+        assert_eq!(Checked(-7) / Checked(2), Checked(-3));
+        assert_eq!(Checked(-7) % Checked(2), Checked(-1));
+        assert_eq!(Checked(i64::MIN).euclidean_size(), 1 << 63);
+    }
+
+    #[test]
+    fn checked_integers_divide_exactly_by_their_divisors() {
+        assert_eq!(Checked(-12i64).div_exact(3), Some(Checked(-4)));
+        assert_eq!(Checked(7i64).div_exact(2), None);
+        assert_eq!(Checked(7i64).div_exact(0), None);
+        // 300 does not fit in an i8, which leaves it without a quotient.
+        assert_eq!(Checked(0i8).div_exact(300), None);
+        assert_eq!(Wrapping(6u8).div_exact(3), None);
+    }
+
+    #[test]
+    fn wrapping_integers_are_a_commutative_ring_with_zero_divisors() {
+        check_commutative_ring(sample(|x| Wrapping(x as u8)), 2000).unwrap();
+        check_commutative_ring(sample(|x| Wrapping(x as i64)), 2000).unwrap();
+        assert_eq!(Wrapping(128u8) * Wrapping(2), Wrapping(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn checked_add_overflow_panics() {
+        let _ = Checked(i8::MAX) + Checked(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn checked_add_assign_overflow_panics() {
+        let mut x = Checked(i64::MAX);
+        x += Checked(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn checked_sub_overflow_panics() {
+        let _ = Checked(i16::MIN) - Checked(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn checked_sub_assign_overflow_panics() {
+        let mut x = Checked(i32::MIN);
+        x -= Checked(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn checked_mul_overflow_panics() {
+        let _ = Checked(i128::MAX) * Checked(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn checked_mul_assign_overflow_panics() {
+        let mut x = Checked(isize::MIN);
+        x *= Checked(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to negate with overflow")]
+    fn checked_neg_overflow_panics() {
+        let _ = -Checked(i8::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero or with overflow")]
+    fn checked_div_overflow_panics() {
+        let _ = Checked(i8::MIN) / Checked(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero or with overflow")]
+    fn checked_div_assign_by_zero_panics() {
+        let mut x = Checked(1i32);
+        x /= Checked(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn checked_rem_by_zero_panics() {
+        let _ = Checked(1i64) % Checked(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn checked_rem_assign_overflow_panics() {
+        let mut x = Checked(i32::MIN);
+        x %= Checked(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero or with overflow")]
+    fn checked_div_euclid_overflow_panics() {
+        let _ = Euclid::div_euclid(&Checked(i64::MIN), &Checked(-1));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to calculate the remainder with a divisor of zero")]
+    fn checked_rem_euclid_by_zero_panics() {
+        let _ = Euclid::rem_euclid(&Checked(5i16), &Checked(0));
+    }
+
+    #[cfg(feature = "approximate-floats")]
+    #[test]
+    fn floats_have_the_euclidean_function_of_a_field() {
+        assert_eq!(0.0f64.euclidean_size(), 0);
+        assert_eq!((-0.0f32).euclidean_size(), 0);
+        assert_eq!(1e-300f64.euclidean_size(), 1);
+        assert_eq!((-3.5f32).euclidean_size(), 1);
+        assert_eq!(f64::INFINITY.euclidean_size(), 1);
+    }
+}
@@ -0,0 +1,383 @@
+//! Direct products of algebraic structures.
+//!
+//! The standard operator traits cannot be implemented for tuples and arrays from outside the
+//! standard library, so [`DirectProduct`] wraps them instead. Operations act component-wise, and
+//! each impl only requires the components to support the operation, so the blanket hierarchy
+//! classifies the product as an `AdditiveAbelianGroup`, `Ring` or `CommutativeRing` exactly when
+//! every component is one. A product never implements `NoZeroDivisors`, so it is never
+//! classified as an `IntegralDomain`.
+
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    ConditionallySelectable, Distributive, ExactDivision, VectorSpace,
+};
+use num_traits::{One, Zero};
+use std::array;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The direct product of algebraic structures, held as a tuple `(A, B, ...)` or an array `[A; N]`.
+///
+/// # Mathematical Definition
+/// The direct product A × B of two structures is the set of pairs (a, b) with a ∈ A and b ∈ B,
+/// whose operations act component-wise:
+/// - (a₁, b₁) + (a₂, b₂) = (a₁ + a₂, b₁ + b₂)
+/// - (a₁, b₁) · (a₂, b₂) = (a₁ · a₂, b₁ · b₂)
+///
+/// # Properties
+/// - The product of rings is a ring, but not an integral domain when it has two or more
+///   non-trivial components: (1, 0) · (0, 1) = (0, 0). `DirectProduct` therefore does not
+///   implement [`NoZeroDivisors`](crate::NoZeroDivisors).
+/// - The product of vector spaces over F is a vector space over F, with dimension the sum of the
+///   dimensions of its components
+///
+/// Products of residue rings Z/m₁ × ... × Z/mₖ with pairwise coprime moduli are, by the Chinese
+/// Remainder Theorem, isomorphic to Z/(m₁⋯mₖ), which is the basis of residue number systems.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DirectProduct<T>(pub T);
+
+impl<T> DirectProduct<T> {
+    /// Returns the tuple or array of components.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for DirectProduct<T> {
+    fn from(components: T) -> Self {
+        DirectProduct(components)
+    }
+}
+
+macro_rules! tuple_product {
+    ($($T:ident $i:tt),+) => {
+        impl<$($T: Add<Output = $T>),+> Add for DirectProduct<($($T,)+)> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                DirectProduct(($(self.0.$i + rhs.0.$i,)+))
+            }
+        }
+
+        impl<$($T: AddAssign),+> AddAssign for DirectProduct<($($T,)+)> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.0.$i += rhs.0.$i;)+
+            }
+        }
+
+        impl<$($T: Sub<Output = $T>),+> Sub for DirectProduct<($($T,)+)> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                DirectProduct(($(self.0.$i - rhs.0.$i,)+))
+            }
+        }
+
+        impl<$($T: SubAssign),+> SubAssign for DirectProduct<($($T,)+)> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.0.$i -= rhs.0.$i;)+
+            }
+        }
+
+        impl<$($T: Neg<Output = $T>),+> Neg for DirectProduct<($($T,)+)> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                DirectProduct(($(-self.0.$i,)+))
+            }
+        }
+
+        impl<$($T: Mul<Output = $T>),+> Mul for DirectProduct<($($T,)+)> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                DirectProduct(($(self.0.$i * rhs.0.$i,)+))
+            }
+        }
+
+        impl<$($T: MulAssign),+> MulAssign for DirectProduct<($($T,)+)> {
+            fn mul_assign(&mut self, rhs: Self) {
+                $(self.0.$i *= rhs.0.$i;)+
+            }
+        }
+
+        impl<$($T: Zero),+> Zero for DirectProduct<($($T,)+)> {
+            fn zero() -> Self {
+                DirectProduct(($($T::zero(),)+))
+            }
+
+            fn is_zero(&self) -> bool {
+                $(self.0.$i.is_zero())&&+
+            }
+        }
+
+        impl<$($T: One),+> One for DirectProduct<($($T,)+)> {
+            fn one() -> Self {
+                DirectProduct(($($T::one(),)+))
+            }
+        }
+
+        impl<$($T: CommutativeAddition),+> CommutativeAddition for DirectProduct<($($T,)+)> {}
+        impl<$($T: AssociativeAddition),+> AssociativeAddition for DirectProduct<($($T,)+)> {
+            const BOUNDED: bool = false $(|| $T::BOUNDED)+;
+        }
+        impl<$($T: CommutativeMultiplication),+> CommutativeMultiplication
+            for DirectProduct<($($T,)+)> {}
+        impl<$($T: AssociativeMultiplication),+> AssociativeMultiplication
+            for DirectProduct<($($T,)+)> {}
+        impl<$($T: Distributive),+> Distributive for DirectProduct<($($T,)+)> {}
+
+        impl<$($T: ExactDivision),+> ExactDivision for DirectProduct<($($T,)+)> {
+            fn div_exact(&self, n: u32) -> Option<Self> {
+                Some(DirectProduct(($(self.0.$i.div_exact(n)?,)+)))
+            }
+        }
+
+        impl<$($T: ConditionallySelectable),+> ConditionallySelectable
+            for DirectProduct<($($T,)+)>
+        {
+            fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+                DirectProduct(($($T::conditional_select(&a.0.$i, &b.0.$i, choice),)+))
+            }
+        }
+    };
+}
+
+tuple_product!(A 0, B 1);
+tuple_product!(A 0, B 1, C 2);
+tuple_product!(A 0, B 1, C 2, D 3);
+
+// Vector spaces are only products of components sharing a scalar field, so the tuple impls are
+// written out per arity.
+
+impl<A, B> VectorSpace for DirectProduct<(A, B)>
+where
+    A: VectorSpace,
+    B: VectorSpace<Scalar = A::Scalar>,
+{
+    type Scalar = A::Scalar;
+
+    fn scale(&self, scalar: &Self::Scalar) -> Self {
+        DirectProduct((self.0 .0.scale(scalar), self.0 .1.scale(scalar)))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.0 .0.dimension()? + self.0 .1.dimension()?)
+    }
+}
+
+impl<A, B, C> VectorSpace for DirectProduct<(A, B, C)>
+where
+    A: VectorSpace,
+    B: VectorSpace<Scalar = A::Scalar>,
+    C: VectorSpace<Scalar = A::Scalar>,
+{
+    type Scalar = A::Scalar;
+
+    fn scale(&self, scalar: &Self::Scalar) -> Self {
+        DirectProduct((
+            self.0 .0.scale(scalar),
+            self.0 .1.scale(scalar),
+            self.0 .2.scale(scalar),
+        ))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.0 .0.dimension()? + self.0 .1.dimension()? + self.0 .2.dimension()?)
+    }
+}
+
+impl<A, B, C, D> VectorSpace for DirectProduct<(A, B, C, D)>
+where
+    A: VectorSpace,
+    B: VectorSpace<Scalar = A::Scalar>,
+    C: VectorSpace<Scalar = A::Scalar>,
+    D: VectorSpace<Scalar = A::Scalar>,
+{
+    type Scalar = A::Scalar;
+
+    fn scale(&self, scalar: &Self::Scalar) -> Self {
+        DirectProduct((
+            self.0 .0.scale(scalar),
+            self.0 .1.scale(scalar),
+            self.0 .2.scale(scalar),
+            self.0 .3.scale(scalar),
+        ))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(
+            self.0 .0.dimension()?
+                + self.0 .1.dimension()?
+                + self.0 .2.dimension()?
+                + self.0 .3.dimension()?,
+        )
+    }
+}
+
+// Arrays
+
+impl<A: Add<Output = A>, const N: usize> Add for DirectProduct<[A; N]> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut rhs = rhs.0.into_iter();
+        DirectProduct(self.0.map(|a| a + rhs.next().unwrap()))
+    }
+}
+
+impl<A: AddAssign, const N: usize> AddAssign for DirectProduct<[A; N]> {
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a += b;
+        }
+    }
+}
+
+impl<A: Sub<Output = A>, const N: usize> Sub for DirectProduct<[A; N]> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let mut rhs = rhs.0.into_iter();
+        DirectProduct(self.0.map(|a| a - rhs.next().unwrap()))
+    }
+}
+
+impl<A: SubAssign, const N: usize> SubAssign for DirectProduct<[A; N]> {
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a -= b;
+        }
+    }
+}
+
+impl<A: Neg<Output = A>, const N: usize> Neg for DirectProduct<[A; N]> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        DirectProduct(self.0.map(|a| -a))
+    }
+}
+
+impl<A: Mul<Output = A>, const N: usize> Mul for DirectProduct<[A; N]> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut rhs = rhs.0.into_iter();
+        DirectProduct(self.0.map(|a| a * rhs.next().unwrap()))
+    }
+}
+
+impl<A: MulAssign, const N: usize> MulAssign for DirectProduct<[A; N]> {
+    fn mul_assign(&mut self, rhs: Self) {
+        for (a, b) in self.0.iter_mut().zip(rhs.0) {
+            *a *= b;
+        }
+    }
+}
+
+impl<A: Zero, const N: usize> Zero for DirectProduct<[A; N]> {
+    fn zero() -> Self {
+        DirectProduct(array::from_fn(|_| A::zero()))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(A::is_zero)
+    }
+}
+
+impl<A: One, const N: usize> One for DirectProduct<[A; N]> {
+    fn one() -> Self {
+        DirectProduct(array::from_fn(|_| A::one()))
+    }
+}
+
+impl<A: CommutativeAddition, const N: usize> CommutativeAddition for DirectProduct<[A; N]> {}
+impl<A: AssociativeAddition, const N: usize> AssociativeAddition for DirectProduct<[A; N]> {
+    const BOUNDED: bool = A::BOUNDED;
+}
+impl<A: CommutativeMultiplication, const N: usize> CommutativeMultiplication
+    for DirectProduct<[A; N]>
+{
+}
+impl<A: AssociativeMultiplication, const N: usize> AssociativeMultiplication
+    for DirectProduct<[A; N]>
+{
+}
+impl<A: Distributive, const N: usize> Distributive for DirectProduct<[A; N]> {}
+
+impl<A: ExactDivision, const N: usize> ExactDivision for DirectProduct<[A; N]> {
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        let quotients: Vec<A> = self
+            .0
+            .iter()
+            .map(|a| a.div_exact(n))
+            .collect::<Option<_>>()?;
+        quotients.try_into().ok().map(DirectProduct)
+    }
+}
+
+impl<A: ConditionallySelectable, const N: usize> ConditionallySelectable for DirectProduct<[A; N]> {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        DirectProduct(array::from_fn(|i| {
+            A::conditional_select(&a.0[i], &b.0[i], choice)
+        }))
+    }
+}
+
+impl<A: VectorSpace, const N: usize> VectorSpace for DirectProduct<[A; N]> {
+    type Scalar = A::Scalar;
+
+    fn scale(&self, scalar: &Self::Scalar) -> Self {
+        DirectProduct(array::from_fn(|i| self.0[i].scale(scalar)))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .try_fold(0, |total, a| Some(total + a.dimension()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::{check_commutative_ring, check_commutative_ring_exhaustive};
+    use crate::{CommutativeRing, FiniteSet, Zn};
+
+    type Z35 = DirectProduct<(Zn<5>, Zn<7>)>;
+
+    impl FiniteSet for Z35 {
+        fn elements() -> impl Iterator<Item = Self> {
+            Zn::<5>::elements()
+                .flat_map(|a| Zn::<7>::elements().map(move |b| DirectProduct((a, b))))
+        }
+    }
+
+    fn is_commutative_ring<T: CommutativeRing>() {}
+
+    #[test]
+    fn products_of_fields_are_commutative_rings() {
+        is_commutative_ring::<Z35>();
+        is_commutative_ring::<DirectProduct<[Zn<3>; 4]>>();
+        check_commutative_ring_exhaustive::<Z35>().unwrap();
+
+        let mut seed = 0u64;
+        let gen = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            DirectProduct(array::from_fn(|i| Zn::<3>::new(seed >> (8 * i + 32))))
+        };
+        check_commutative_ring::<DirectProduct<[Zn<3>; 4]>, _>(gen, 200).unwrap();
+    }
+
+    #[test]
+    fn products_have_zero_divisors() {
+        let a = DirectProduct((Zn::<5>::one(), Zn::<7>::zero()));
+        let b = DirectProduct((Zn::<5>::zero(), Zn::<7>::one()));
+        assert!(!a.is_zero() && !b.is_zero());
+        assert!((a * b).is_zero());
+
+        let a = DirectProduct([Zn::<3>::one(), Zn::zero()]);
+        let b = DirectProduct([Zn::<3>::zero(), Zn::one()]);
+        assert!((a * b).is_zero());
+    }
+}
@@ -0,0 +1,537 @@
+//! Unsigned integers of 64-bit limbs, of a fixed width ([`UInt`]) or arbitrary precision
+//! ([`BigUInt`]).
+
+use num_traits::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+/// An unsigned integer of `L` little-endian 64-bit limbs, below 2⁶⁴ᴸ.
+///
+/// This is the scalar type used to report the characteristic and order of prime fields whose
+/// modulus does not fit in a primitive integer, such as 256-bit prime fields. The orders of their
+/// extensions are reported in [`BigUInt`]. Arithmetic is exact and panics on overflow.
+///
+/// # Example
+/// ```
+/// use noether::UInt;
+///
+/// let p = UInt::<4>::from(1_000_003);
+/// assert_eq!(p.pow(3), UInt::from(1_000_003) * UInt::from(1_000_003) * p);
+/// assert_eq!(p.to_string(), "1000003");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UInt<const L: usize>([u64; L]);
+
+impl<const L: usize> UInt<L> {
+    /// Creates the integer with the given little-endian limbs.
+    pub const fn from_limbs(limbs: [u64; L]) -> Self {
+        UInt(limbs)
+    }
+
+    /// Returns the little-endian limbs of the integer.
+    pub const fn as_limbs(&self) -> &[u64; L] {
+        &self.0
+    }
+
+    /// Returns the number of significant bits, zero for zero.
+    pub fn bits(&self) -> u32 {
+        match self.0.iter().rposition(|&limb| limb != 0) {
+            Some(i) => i as u32 * 64 + 64 - self.0[i].leading_zeros(),
+            None => 0,
+        }
+    }
+
+    /// Returns `self + rhs`, or `None` on overflow.
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let mut result = [0; L];
+        let mut carry = 0u64;
+        for (i, limb) in result.iter_mut().enumerate() {
+            let sum = self.0[i] as u128 + rhs.0[i] as u128 + carry as u128;
+            *limb = sum as u64;
+            carry = (sum >> 64) as u64;
+        }
+        (carry == 0).then_some(UInt(result))
+    }
+
+    /// Returns `self * rhs`, or `None` on overflow.
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let mut result = [0; L];
+        for (i, &a) in self.0.iter().enumerate() {
+            if a == 0 {
+                continue;
+            }
+            let mut carry = 0u64;
+            for (j, &b) in rhs.0.iter().enumerate() {
+                let product = a as u128 * b as u128 + carry as u128;
+                if i + j >= L {
+                    if product != 0 {
+                        return None;
+                    }
+                    continue;
+                }
+                let sum = product + result[i + j] as u128;
+                result[i + j] = sum as u64;
+                carry = (sum >> 64) as u64;
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        Some(UInt(result))
+    }
+
+    /// Returns `self` raised to the power `exp`.
+    ///
+    /// # Panics
+    /// Panics if the result does not fit in `L` limbs.
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = *self;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base *= base;
+            }
+        }
+        result
+    }
+
+    /// Divides `self` in place by a nonzero `divisor`, returning the remainder.
+    fn div_rem_small(&mut self, divisor: u64) -> u64 {
+        div_rem_limbs(&mut self.0, divisor)
+    }
+}
+
+impl<const L: usize> From<u64> for UInt<L> {
+    fn from(value: u64) -> Self {
+        let mut limbs = [0; L];
+        limbs[0] = value;
+        UInt(limbs)
+    }
+}
+
+impl<const L: usize> Ord for UInt<L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The most significant limb decides.
+        self.0.iter().rev().cmp(other.0.iter().rev())
+    }
+}
+
+impl<const L: usize> PartialOrd for UInt<L> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const L: usize> Add for UInt<L> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(&rhs)
+            .expect("attempt to add with overflow")
+    }
+}
+
+impl<const L: usize> AddAssign for UInt<L> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const L: usize> Mul for UInt<L> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(&rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl<const L: usize> MulAssign for UInt<L> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const L: usize> Zero for UInt<L> {
+    fn zero() -> Self {
+        UInt([0; L])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|&limb| limb == 0)
+    }
+}
+
+impl<const L: usize> One for UInt<L> {
+    fn one() -> Self {
+        Self::from(1)
+    }
+}
+
+impl<const L: usize> fmt::Debug for UInt<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UInt({self})")
+    }
+}
+
+impl<const L: usize> fmt::Display for UInt<L> {
+    /// Formats the integer in decimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Peel off base 10¹⁹ digits, the largest power of ten below 2⁶⁴.
+        const BASE: u64 = 10_000_000_000_000_000_000;
+        let mut value = *self;
+        let mut digits = Vec::new();
+        loop {
+            digits.push(value.div_rem_small(BASE));
+            if value.is_zero() {
+                break;
+            }
+        }
+        let mut digits = digits.iter().rev();
+        write!(f, "{}", digits.next().unwrap())?;
+        for digit in digits {
+            write!(f, "{digit:019}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<const L: usize> fmt::LowerHex for UInt<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = self.0.iter().rev().skip_while(|&&limb| limb == 0);
+        match limbs.next() {
+            Some(first) => write!(f, "{first:x}")?,
+            None => write!(f, "0")?,
+        }
+        for limb in limbs {
+            write!(f, "{limb:016x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Divides the little-endian limbs in place by a nonzero `divisor`, returning the remainder.
+fn div_rem_limbs(limbs: &mut [u64], divisor: u64) -> u64 {
+    let mut remainder = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let current = (remainder << 64) | *limb as u128;
+        *limb = (current / divisor as u128) as u64;
+        remainder = current % divisor as u128;
+    }
+    remainder as u64
+}
+
+/// An unsigned integer of arbitrary precision, stored as little-endian 64-bit limbs.
+///
+/// Every [`FiniteField::ScalarType`](crate::FiniteField::ScalarType) converts into it, and it is
+/// the scalar type of field extensions, whose order pⁿ outgrows the integer type of the
+/// characteristic p: the order of the degree 12 extension of a 254-bit prime field has 3048
+/// bits. Arithmetic is exact and never overflows.
+///
+/// # Example
+/// ```
+/// use noether::{BigUInt, UInt};
+///
+/// let p = BigUInt::from(UInt::<4>::from_limbs([0, 0, 0, 1 << 62]));
+/// assert_eq!(p.bits(), 255);
+/// assert_eq!(p.pow(12).bits(), 12 * 254 + 1);
+///
+/// let m = BigUInt::from(u64::MAX);
+/// assert_eq!(m.clone() * m.clone() + m.clone() + m, BigUInt::from(u128::MAX));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUInt(Vec<u64>);
+
+impl BigUInt {
+    /// Creates the integer with the given little-endian limbs.
+    pub fn from_limbs(limbs: &[u64]) -> Self {
+        let mut value = BigUInt(limbs.to_vec());
+        value.normalize();
+        value
+    }
+
+    /// Returns the little-endian limbs of the integer, without leading zero limbs.
+    pub fn as_limbs(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Returns the number of significant bits, zero for zero.
+    pub fn bits(&self) -> u32 {
+        match self.0.last() {
+            Some(last) => self.0.len() as u32 * 64 - last.leading_zeros(),
+            None => 0,
+        }
+    }
+
+    /// Returns `self` raised to the power `exp`.
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Returns `self - rhs`, or `None` if `rhs` is larger.
+    pub(crate) fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        if *self < *rhs {
+            return None;
+        }
+        let mut result = self.0.clone();
+        let mut borrow = false;
+        for (i, limb) in result.iter_mut().enumerate() {
+            let (difference, b1) = limb.overflowing_sub(rhs.0.get(i).copied().unwrap_or(0));
+            let (difference, b2) = difference.overflowing_sub(borrow as u64);
+            *limb = difference;
+            borrow = b1 || b2;
+        }
+        let mut result = BigUInt(result);
+        result.normalize();
+        Some(result)
+    }
+
+    /// Returns the quotient and remainder of the division by a nonzero `divisor`.
+    pub(crate) fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        let mut quotient = self.clone();
+        let remainder = div_rem_limbs(&mut quotient.0, divisor);
+        quotient.normalize();
+        (quotient, remainder)
+    }
+
+    /// Drops the leading zero limbs, so that equal integers have equal limbs.
+    fn normalize(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+}
+
+impl From<u64> for BigUInt {
+    fn from(value: u64) -> Self {
+        BigUInt::from_limbs(&[value])
+    }
+}
+
+impl From<u128> for BigUInt {
+    fn from(value: u128) -> Self {
+        BigUInt::from_limbs(&[value as u64, (value >> 64) as u64])
+    }
+}
+
+impl<const L: usize> From<UInt<L>> for BigUInt {
+    fn from(value: UInt<L>) -> Self {
+        BigUInt::from_limbs(value.as_limbs())
+    }
+}
+
+impl Ord for BigUInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Normalized integers with more limbs are larger; otherwise the most significant limb
+        // decides.
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigUInt> for &BigUInt {
+    type Output = BigUInt;
+
+    fn add(self, rhs: &BigUInt) -> BigUInt {
+        let (long, short) = if self.0.len() >= rhs.0.len() {
+            (&self.0, &rhs.0)
+        } else {
+            (&rhs.0, &self.0)
+        };
+        let mut result = Vec::with_capacity(long.len() + 1);
+        let mut carry = 0u64;
+        for (i, &a) in long.iter().enumerate() {
+            let sum = a as u128 + short.get(i).copied().unwrap_or(0) as u128 + carry as u128;
+            result.push(sum as u64);
+            carry = (sum >> 64) as u64;
+        }
+        if carry != 0 {
+            result.push(carry);
+        }
+        BigUInt(result)
+    }
+}
+
+impl Add for BigUInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl AddAssign for BigUInt {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = &*self + &rhs;
+    }
+}
+
+impl Mul<&BigUInt> for &BigUInt {
+    type Output = BigUInt;
+
+    fn mul(self, rhs: &BigUInt) -> BigUInt {
+        if self.0.is_empty() || rhs.0.is_empty() {
+            return BigUInt::zero();
+        }
+        let mut result = vec![0u64; self.0.len() + rhs.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u64;
+            for (j, &b) in rhs.0.iter().enumerate() {
+                let sum = a as u128 * b as u128 + result[i + j] as u128 + carry as u128;
+                result[i + j] = sum as u64;
+                carry = (sum >> 64) as u64;
+            }
+            result[i + rhs.0.len()] = carry;
+        }
+        let mut result = BigUInt(result);
+        result.normalize();
+        result
+    }
+}
+
+impl Mul for BigUInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl MulAssign for BigUInt {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl Zero for BigUInt {
+    fn zero() -> Self {
+        BigUInt(Vec::new())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl One for BigUInt {
+    fn one() -> Self {
+        BigUInt(vec![1])
+    }
+}
+
+impl fmt::Debug for BigUInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BigUInt({self})")
+    }
+}
+
+impl fmt::Display for BigUInt {
+    /// Formats the integer in decimal.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Peel off base 10¹⁹ digits, the largest power of ten below 2⁶⁴.
+        const BASE: u64 = 10_000_000_000_000_000_000;
+        let mut value = self.0.clone();
+        let mut digits = Vec::new();
+        loop {
+            digits.push(div_rem_limbs(&mut value, BASE));
+            while value.last() == Some(&0) {
+                value.pop();
+            }
+            if value.is_empty() {
+                break;
+            }
+        }
+        let mut digits = digits.iter().rev();
+        write!(f, "{}", digits.next().unwrap())?;
+        for digit in digits {
+            write!(f, "{digit:019}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::LowerHex for BigUInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut limbs = self.0.iter().rev();
+        match limbs.next() {
+            Some(first) => write!(f, "{first:x}")?,
+            None => write!(f, "0")?,
+        }
+        for limb in limbs {
+            write!(f, "{limb:016x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_arithmetic_carries_across_limbs() {
+        let max = BigUInt::from(u64::MAX);
+        assert_eq!(&max + &BigUInt::one(), BigUInt::from_limbs(&[0, 1]));
+        assert_eq!(&max * &max, BigUInt::from_limbs(&[1, u64::MAX - 1]));
+        assert_eq!(BigUInt::from_limbs(&[5, 0, 0]).as_limbs(), &[5]);
+        assert!(BigUInt::zero().as_limbs().is_empty());
+        assert!((&max * &BigUInt::zero()).is_zero());
+        assert_eq!(
+            BigUInt::from_limbs(&[0, 1]).checked_sub(&BigUInt::one()),
+            Some(max.clone())
+        );
+        assert_eq!(max.checked_sub(&max), Some(BigUInt::zero()));
+        assert_eq!(max.checked_sub(&BigUInt::from_limbs(&[0, 1])), None);
+    }
+
+    #[test]
+    fn big_powers_agree_with_fixed_width() {
+        let p = UInt::<4>::from(1_000_003);
+        assert_eq!(BigUInt::from(p).pow(5), BigUInt::from(p.pow(5)));
+        // 2²⁵⁶ no longer fits in four limbs.
+        let two = BigUInt::from(2u64);
+        assert_eq!(two.pow(256).as_limbs(), &[0, 0, 0, 0, 1]);
+        assert_eq!(two.pow(256).bits(), 257);
+        assert_eq!(two.pow(0), BigUInt::one());
+    }
+
+    #[test]
+    fn big_ordering_and_formatting() {
+        assert!(BigUInt::from_limbs(&[0, 1]) > BigUInt::from(u64::MAX));
+        assert!(BigUInt::from_limbs(&[1, 2]) < BigUInt::from_limbs(&[0, 3]));
+        assert_eq!(BigUInt::zero().to_string(), "0");
+        assert_eq!(
+            BigUInt::from(10u64).pow(40).to_string(),
+            format!("1{}", "0".repeat(40))
+        );
+        assert_eq!(
+            format!("{:x}", BigUInt::from_limbs(&[1, 1])),
+            "10000000000000001"
+        );
+        assert_eq!(format!("{:?}", BigUInt::from(7u64)), "BigUInt(7)");
+    }
+}
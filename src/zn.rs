@@ -0,0 +1,729 @@
+//! Integers modulo a compile-time modulus: the ring [`Zn`], and the prime field [`Fp`].
+
+use crate::{
+    AssociativeAddition, AssociativeMultiplication, CommutativeAddition, CommutativeMultiplication,
+    ConditionallySelectable, Distributive, EuclideanValuation, ExactDivision, FiniteField,
+    FiniteSet, NoZeroDivisors, TwoAdicField,
+};
+use num_traits::{Euclid, Inv, One, Zero};
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Represents the ring of integers modulo N, ℤ/Nℤ.
+///
+/// # Mathematical Definition
+/// ℤ/Nℤ is the set {0, 1, ..., N - 1} where addition and multiplication are performed on the
+/// integers and reduced modulo N.
+///
+/// # Properties
+/// - ℤ/Nℤ is a commutative ring for every N > 1
+/// - ℤ/Nℤ has zero divisors when N is composite, such as 2 · 3 = 0 in ℤ/6ℤ
+///
+/// `Zn<N>` is a [`CommutativeRing`](crate::CommutativeRing) for every modulus, and never claims
+/// [`NoZeroDivisors`]. The field ℤ/pℤ of a prime p is [`Fp<P>`], which can only be used with a
+/// prime modulus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Zn<const N: u64>(u64);
+
+impl<const N: u64> Zn<N> {
+    /// Evaluates to `()` when the modulus is valid, and fails to compile otherwise.
+    const VALID_MODULUS: () = assert!(N > 1, "the modulus of Zn must be greater than one");
+
+    /// Creates the residue class of `value` modulo N.
+    pub const fn new(value: u64) -> Self {
+        let () = Self::VALID_MODULUS;
+        Zn(value % N)
+    }
+
+    /// Returns the canonical representative of the residue class, in `0..N`.
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the modulus N.
+    pub const fn modulus() -> u64 {
+        N
+    }
+}
+
+/// Represents the prime field 𝔽ₚ = ℤ/pℤ for a prime p = P below 2⁶⁴.
+///
+/// # Mathematical Definition
+/// 𝔽ₚ is the ring ℤ/pℤ, in which every nonzero residue a has an inverse a⁻¹ with a · a⁻¹ = 1.
+///
+/// **Only prime moduli are valid.** Every element is created through a constant assertion that
+/// P is prime, so any use of `Fp<P>` with a composite P, from [`Fp::new`] and the identities to
+/// the [`FiniteField`] methods, fails to compile. Use [`Zn<N>`] for the ring of a composite
+/// modulus.
+///
+/// # Example
+/// ```
+/// use noether::{FiniteField, Fp, Zn};
+///
+/// type F7 = Fp<7>;
+///
+/// assert_eq!(F7::new(3) / F7::new(5), F7::new(2));
+/// assert_eq!(F7::order(), 7);
+/// assert_eq!(Zn::from(F7::new(6)), Zn::new(6));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fp<const P: u64>(Zn<P>);
+
+impl<const P: u64> Fp<P> {
+    /// Evaluates to `()` when the modulus is prime, and fails to compile otherwise.
+    const PRIME_MODULUS: () = assert!(is_prime(P), "the modulus of Fp must be prime");
+
+    /// Creates the residue class of `value` modulo P.
+    pub const fn new(value: u64) -> Self {
+        let () = Self::PRIME_MODULUS;
+        Fp(Zn::new(value))
+    }
+
+    /// Returns the canonical representative of the residue class, in `0..P`.
+    pub const fn value(&self) -> u64 {
+        self.0.value()
+    }
+
+    /// Returns the modulus P.
+    pub const fn modulus() -> u64 {
+        P
+    }
+
+    /// A primitive root of unity of order 2ˢ, the largest power of two dividing P - 1.
+    const TWO_ADIC_ROOT: u64 = root_of_unity(P, 1 << (P - 1).trailing_zeros());
+
+    /// A primitive root of unity of order 2ˢ · 3ʲ, the largest such number dividing P - 1.
+    const LARGE_SUBGROUP_ROOT: u64 = root_of_unity(
+        P,
+        (1 << (P - 1).trailing_zeros()) * 3u64.pow(adicity(P - 1, 3)),
+    );
+}
+
+/// Returns `base^exp mod modulus`.
+const fn pow_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Returns the inverse of `a` modulo `modulus`, or `None` if they are not coprime.
+pub(crate) fn inverse_mod(a: u64, modulus: u64) -> Option<u64> {
+    // Extended Euclidean algorithm on (modulus, a), tracking the coefficient of a.
+    let (mut r0, mut r1) = (modulus as i128, a as i128);
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    (r0 == 1).then(|| t0.rem_euclid(modulus as i128) as u64)
+}
+
+/// Returns the largest j such that `base`ʲ divides the nonzero `n`.
+const fn adicity(mut n: u64, base: u64) -> u32 {
+    let mut j = 0;
+    while n % base == 0 {
+        n /= base;
+        j += 1;
+    }
+    j
+}
+
+/// Returns a primitive root of unity of the given order modulo the prime `p`, for an order
+/// dividing p - 1 whose prime factors are 2 and 3.
+///
+/// Searches for the smallest g such that g^((p - 1) / ℓ) ≠ 1 for each prime ℓ dividing the
+/// order, so that g^((p - 1) / order) has exactly that order.
+const fn root_of_unity(p: u64, order: u64) -> u64 {
+    let mut g = 2;
+    while g < p {
+        let residue_2 = order % 2 == 0 && pow_mod(g, (p - 1) / 2, p) == 1;
+        let residue_3 = order % 3 == 0 && pow_mod(g, (p - 1) / 3, p) == 1;
+        if !residue_2 && !residue_3 {
+            return pow_mod(g, (p - 1) / order, p);
+        }
+        g += 1;
+    }
+    // Only reached for p = 2, where the order is 1.
+    1
+}
+
+/// Returns whether `n` is prime.
+///
+/// Uses the Miller-Rabin test with the first twelve primes as witnesses, which is deterministic
+/// for every n < 2⁶⁴.
+const fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        let p = WITNESSES[i];
+        if n % p == 0 {
+            return n == p;
+        }
+        i += 1;
+    }
+
+    // n - 1 = d · 2ˢ with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        let mut x = pow_mod(WITNESSES[i], d, n);
+        if x != 1 && x != n - 1 {
+            let mut r = 1;
+            while r < s {
+                x = ((x as u128 * x as u128) % n as u128) as u64;
+                if x == n - 1 {
+                    break;
+                }
+                r += 1;
+            }
+            if x != n - 1 {
+                return false;
+            }
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<const N: u64> From<u64> for Zn<N> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const N: u64> fmt::Display for Zn<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const N: u64> Add for Zn<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Zn(((self.0 as u128 + rhs.0 as u128) % N as u128) as u64)
+    }
+}
+
+impl<const N: u64> Sub for Zn<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<const N: u64> Neg for Zn<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Zn(N - self.0)
+        }
+    }
+}
+
+impl<const N: u64> Mul for Zn<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Zn(((self.0 as u128 * rhs.0 as u128) % N as u128) as u64)
+    }
+}
+
+impl<const N: u64> AddAssign for Zn<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: u64> SubAssign for Zn<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: u64> MulAssign for Zn<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: u64> Zero for Zn<N> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const N: u64> One for Zn<N> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const N: u64> ExactDivision for Zn<N> {
+    /// Multiplies by the inverse of n modulo N, which exists when n and N are coprime, so that
+    /// this also holds for a composite N.
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        inverse_mod(n as u64 % N, N).map(|inverse| *self * Zn(inverse))
+    }
+}
+
+impl<const N: u64> ConditionallySelectable for Zn<N> {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        let mask = 0u64.wrapping_sub(choice);
+        Zn((a.0 & !mask) | (b.0 & mask))
+    }
+}
+
+impl<const N: u64> CommutativeAddition for Zn<N> {}
+impl<const N: u64> AssociativeAddition for Zn<N> {}
+impl<const N: u64> CommutativeMultiplication for Zn<N> {}
+impl<const N: u64> AssociativeMultiplication for Zn<N> {}
+impl<const N: u64> Distributive for Zn<N> {}
+
+impl<const N: u64> FiniteSet for Zn<N> {
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..N).map(Self::new)
+    }
+}
+
+// The field operations of Fp are those of Zn, which the prime modulus makes a field.
+
+impl<const P: u64> From<u64> for Fp<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<Fp<P>> for Zn<P> {
+    fn from(value: Fp<P>) -> Self {
+        value.0
+    }
+}
+
+impl<const P: u64> fmt::Display for Fp<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<const P: u64> Add for Fp<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Fp(self.0 + rhs.0)
+    }
+}
+
+impl<const P: u64> Sub for Fp<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Fp(self.0 - rhs.0)
+    }
+}
+
+impl<const P: u64> Neg for Fp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fp(-self.0)
+    }
+}
+
+impl<const P: u64> Mul for Fp<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Fp(self.0 * rhs.0)
+    }
+}
+
+impl<const P: u64> Inv for Fp<P> {
+    type Output = Self;
+
+    /// Returns the multiplicative inverse.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    fn inv(self) -> Self {
+        assert!(!self.is_zero(), "attempt to invert zero in Fp");
+        let inverse = inverse_mod(self.value(), P);
+        Fp(Zn(
+            inverse.expect("nonzero residues are units modulo a prime")
+        ))
+    }
+}
+
+impl<const P: u64> ExactDivision for Fp<P> {
+    /// Multiplies by the inverse of n, which exists unless p divides n.
+    fn div_exact(&self, n: u32) -> Option<Self> {
+        self.0.div_exact(n).map(Fp)
+    }
+}
+
+impl<const P: u64> ConditionallySelectable for Fp<P> {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        Fp(Zn::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<const P: u64> Div for Fp<P> {
+    type Output = Self;
+
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl<const P: u64> Rem for Fp<P> {
+    type Output = Self;
+
+    /// Returns the remainder of field division, which is always zero.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is zero.
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            !rhs.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        Self::zero()
+    }
+}
+
+impl<const P: u64> AddAssign for Fp<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for Fp<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for Fp<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> DivAssign for Fp<P> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const P: u64> RemAssign for Fp<P> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl<const P: u64> Zero for Fp<P> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<const P: u64> One for Fp<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64> Euclid for Fp<P> {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl<const P: u64> EuclideanValuation for Fp<P> {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(!self.is_zero())
+    }
+}
+
+impl<const P: u64> CommutativeAddition for Fp<P> {}
+impl<const P: u64> AssociativeAddition for Fp<P> {}
+impl<const P: u64> CommutativeMultiplication for Fp<P> {}
+impl<const P: u64> AssociativeMultiplication for Fp<P> {}
+impl<const P: u64> Distributive for Fp<P> {}
+impl<const P: u64> NoZeroDivisors for Fp<P> {}
+
+impl<const P: u64> FiniteSet for Fp<P> {
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..P).map(Self::new)
+    }
+}
+
+impl<const P: u64> FiniteField for Fp<P> {
+    type ScalarType = u64;
+
+    fn characteristic() -> u64 {
+        let () = Self::PRIME_MODULUS;
+        P
+    }
+
+    fn order() -> u64 {
+        let () = Self::PRIME_MODULUS;
+        P
+    }
+
+    fn extension_degree() -> u32 {
+        1
+    }
+
+    /// Returns `self`, since aᵖ = a for every a in 𝔽ₚ.
+    fn absolute_frobenius_map(&self, _power: usize) -> Self {
+        *self
+    }
+}
+
+impl<const P: u64> TwoAdicField for Fp<P> {
+    const TWO_ADICITY: u32 = {
+        let () = Self::PRIME_MODULUS;
+        (P - 1).trailing_zeros()
+    };
+
+    const SMALL_SUBGROUP_BASE: Option<(u64, u32)> = match adicity(P - 1, 3) {
+        0 => None,
+        j => Some((3, j)),
+    };
+
+    fn two_adic_root_of_unity() -> Self {
+        Self::new(Self::TWO_ADIC_ROOT)
+    }
+
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Self::SMALL_SUBGROUP_BASE.map(|_| Self::new(Self::LARGE_SUBGROUP_ROOT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::laws::{check_commutative_ring_exhaustive, check_field, check_field_exhaustive};
+    use crate::{BatchInversion, Exponentiation};
+
+    #[test]
+    fn small_prime_fields_satisfy_every_axiom() {
+        check_field_exhaustive::<Fp<2>>().unwrap();
+        check_field_exhaustive::<Fp<3>>().unwrap();
+        check_field_exhaustive::<Fp<5>>().unwrap();
+        check_field_exhaustive::<Fp<7>>().unwrap();
+        check_field_exhaustive::<Fp<13>>().unwrap();
+    }
+
+    #[test]
+    fn composite_moduli_give_commutative_rings() {
+        check_commutative_ring_exhaustive::<Zn<4>>().unwrap();
+        check_commutative_ring_exhaustive::<Zn<6>>().unwrap();
+        check_commutative_ring_exhaustive::<Zn<12>>().unwrap();
+        assert!((Zn::<6>::new(2) * Zn::new(3)).is_zero());
+    }
+
+    #[test]
+    fn prime_fields_agree_with_the_ring_of_the_same_modulus() {
+        for a in 0..13 {
+            for b in 0..13 {
+                let (x, y) = (Fp::<13>::new(a), Fp::<13>::new(b));
+                assert_eq!(Zn::from(x + y), Zn::new(a) + Zn::new(b));
+                assert_eq!(Zn::from(x * y), Zn::new(a) * Zn::new(b));
+                assert_eq!(Zn::from(-x), -Zn::<13>::new(a));
+            }
+        }
+    }
+
+    #[test]
+    fn large_moduli_do_not_overflow() {
+        // The largest prime below 2⁶⁴.
+        type F = Fp<18446744073709551557>;
+        let minus_one = -F::one();
+        assert_eq!(minus_one.value(), 18446744073709551556);
+        assert_eq!(minus_one * minus_one, F::one());
+        assert_eq!(minus_one + minus_one, F::new(18446744073709551555));
+        assert_eq!(F::zero() - F::one(), minus_one);
+        assert_eq!(F::new(u64::MAX), F::new(58));
+
+        let mut state = 1u64;
+        check_field(
+            || {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                F::new(state)
+            },
+            500,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn miller_rabin_accepts_primes() {
+        for p in [
+            2,
+            3,
+            5,
+            37,
+            41,
+            1_000_000_007,
+            2_147_483_647,
+            4_294_967_291,
+            2_305_843_009_213_693_951,
+            18_446_744_073_709_551_557,
+        ] {
+            assert!(is_prime(p), "{p} is prime");
+        }
+    }
+
+    #[test]
+    fn miller_rabin_rejects_carmichael_numbers() {
+        for n in [
+            561, 1105, 1729, 2465, 2821, 6601, 8911, 10585, 15841, 29341, 41041, 62745, 63973,
+            75361, 101101, 126217, 172081, 188461, 252601, 278545, 294409, 314821, 334153, 340561,
+            399001, 410041, 449065, 488881, 512461,
+        ] {
+            assert!(!is_prime(n), "{n} is a Carmichael number");
+        }
+    }
+
+    #[test]
+    fn miller_rabin_rejects_strong_pseudoprimes() {
+        // The smallest strong pseudoprimes to the bases 2; 2 and 3; ...; 2 to 23.
+        for n in [
+            2047,
+            1_373_653,
+            25_326_001,
+            3_215_031_751,
+            2_152_302_898_747,
+            3_474_749_660_383,
+            341_550_071_728_321,
+            3_825_123_056_546_413_051,
+        ] {
+            assert!(!is_prime(n), "{n} is a strong pseudoprime");
+        }
+        // More strong pseudoprimes to base 2, squares of witnesses and other composites.
+        for n in [
+            0,
+            1,
+            4,
+            3277,
+            4033,
+            4681,
+            8321,
+            1369,
+            1681,
+            4_294_967_297,
+            u64::MAX,
+        ] {
+            assert!(!is_prime(n), "{n} is not prime");
+        }
+    }
+
+    #[test]
+    fn roots_of_unity_have_the_advertised_order() {
+        type F = Fp<97>;
+        // 96 = 2⁵ · 3
+        let root = F::two_adic_root_of_unity();
+        assert_eq!(F::TWO_ADICITY, 5);
+        assert_eq!(root.square_and_multiply(&[16]), -F::one());
+        assert_eq!(root.square_and_multiply(&[32]), F::one());
+
+        let root = F::large_subgroup_root_of_unity().unwrap();
+        assert_eq!(root.square_and_multiply(&[96]), F::one());
+        assert_ne!(root.square_and_multiply(&[48]), F::one());
+        assert_ne!(root.square_and_multiply(&[32]), F::one());
+    }
+
+    #[test]
+    fn exact_division_multiplies_by_the_inverse() {
+        assert_eq!(Zn::<7>::new(1).div_exact(2), Some(Zn::new(4)));
+        assert_eq!(Zn::<7>::new(5).div_exact(10), Some(Zn::new(4)));
+        assert_eq!(Zn::<7>::new(0).div_exact(7), None);
+        // 3 · 3 = 3 · 7 in ℤ/12, so dividing by 3 has no unique answer.
+        assert_eq!(Zn::<12>::new(9).div_exact(3), None);
+        assert_eq!(Zn::<12>::new(10).div_exact(5), Some(Zn::new(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to invert zero")]
+    fn inverting_zero_panics() {
+        let _ = Fp::<7>::zero().inv();
+    }
+
+    #[test]
+    fn batch_inversion_agrees_with_inversion() {
+        type F = Fp<65537>;
+        for len in [0, 1, 2, 3, 17, 100] {
+            let mut values: Vec<F> = (1..=len).map(|i| F::new(i * i * 7919)).collect();
+            let expected: Vec<F> = values.iter().map(|a| a.inv()).collect();
+            F::batch_invert(&mut values);
+            assert_eq!(values, expected, "len = {len}");
+        }
+    }
+
+    #[test]
+    fn batch_inversion_skips_zeros_anywhere() {
+        type F = Fp<101>;
+        let zero = F::zero();
+        for values in [
+            vec![zero, F::new(2), F::new(3), F::new(4)],
+            vec![F::new(2), F::new(3), zero, F::new(4), F::new(5)],
+            vec![F::new(2), F::new(3), F::new(4), zero],
+            vec![zero, zero, F::new(7), zero, F::new(9), zero, zero],
+            vec![zero, zero],
+            vec![],
+        ] {
+            let expected: Vec<F> = values
+                .iter()
+                .map(|a| if a.is_zero() { zero } else { a.inv() })
+                .collect();
+            let mut inverted = values.clone();
+            F::batch_invert_skipping_zeros(&mut inverted);
+            assert_eq!(inverted, expected, "values = {values:?}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to invert zero in batch_invert")]
+    fn batch_inversion_of_zero_panics() {
+        let mut values = [Fp::<101>::new(2), Fp::zero(), Fp::new(3)];
+        Fp::batch_invert(&mut values);
+    }
+}
@@ -0,0 +1,437 @@
+//! Derives the marker traits on concrete types, which also compiles and runs the law tests the
+//! derives generate.
+
+use noether::num_traits::{Euclid, Inv, One, Zero};
+use noether::{
+    AdditiveAbelianGroup, AssociativeAddition, CommutativeAddition, CommutativeRing,
+    EuclideanValuation, ExactDivision, Field, IntegralDomain, NoZeroDivisors, Ring,
+};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Returns a pseudo-random value below `modulus`.
+fn next(modulus: u8) -> u8 {
+    static STATE: AtomicU64 = AtomicU64::new(0x2545F4914F6CDD1D);
+    let state = STATE
+        .fetch_add(0x9E3779B97F4A7C15, Ordering::Relaxed)
+        .wrapping_mul(0xBF58476D1CE4E5B9);
+    (state >> 32) as u8 % modulus
+}
+
+/// Implements arithmetic modulo `$n` for the newtype `$name(u8)`.
+macro_rules! residues {
+    ($name:ident, $n:expr) => {
+        impl $name {
+            fn new(value: u8) -> Self {
+                $name(value % $n)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                $name::new(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $name::new(self.0 + $n - rhs.0)
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $name::new($n - self.0)
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                $name::new(((self.0 as u16 * rhs.0 as u16) % $n) as u8)
+            }
+        }
+
+        impl MulAssign for $name {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl Zero for $name {
+            fn zero() -> Self {
+                $name(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl One for $name {
+            fn one() -> Self {
+                $name(1)
+            }
+        }
+    };
+}
+
+/// The prime field 𝔽₁₃.
+#[derive(Clone, Copy, Debug, PartialEq, noether::Field)]
+#[noether(generator = f13, samples = 300)]
+struct F13(u8);
+
+residues!(F13, 13);
+
+fn f13() -> F13 {
+    F13::new(next(13))
+}
+
+impl Inv for F13 {
+    type Output = Self;
+
+    fn inv(self) -> Self {
+        assert!(!self.is_zero(), "attempt to invert zero in F13");
+        (1..13).map(F13).find(|&x| self * x == F13(1)).unwrap()
+    }
+}
+
+impl Div for F13 {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.inv()
+    }
+}
+
+impl DivAssign for F13 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Rem for F13 {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        assert!(
+            !rhs.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        F13(0)
+    }
+}
+
+impl RemAssign for F13 {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl Euclid for F13 {
+    fn div_euclid(&self, v: &Self) -> Self {
+        *self / *v
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        *self % *v
+    }
+}
+
+impl EuclideanValuation for F13 {
+    fn euclidean_size(&self) -> u128 {
+        u128::from(!self.is_zero())
+    }
+}
+
+/// The ring ℤ/6, which has zero divisors.
+#[derive(Clone, Copy, Debug, PartialEq, noether::CommutativeRing)]
+#[noether(generator = z6)]
+struct Z6(u8);
+
+residues!(Z6, 6);
+
+fn z6() -> Z6 {
+    Z6::new(next(6))
+}
+
+/// The ring ℤ/8, classified through the single marker derives.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    noether::CommutativeAddition,
+    noether::AssociativeAddition,
+    noether::CommutativeMultiplication,
+    noether::AssociativeMultiplication,
+    noether::Distributive,
+)]
+#[noether(generator = z8, samples = 200)]
+struct Z8(u8);
+
+residues!(Z8, 8);
+
+fn z8() -> Z8 {
+    Z8::new(next(8))
+}
+
+/// The additive group ℤ/10.
+#[derive(Clone, Copy, Debug, PartialEq, noether::AdditiveAbelianGroup)]
+#[noether(generator = z10)]
+struct Z10(u8);
+
+residues!(Z10, 10);
+
+fn z10() -> Z10 {
+    Z10::new(next(10))
+}
+
+/// ℤ/9 promising the absence of zero divisors, which its generator never exposes.
+#[derive(Clone, Copy, Debug, PartialEq, noether::Ring, noether::NoZeroDivisors)]
+#[noether(generator = units_mod_9)]
+struct Z9(u8);
+
+residues!(Z9, 9);
+
+fn units_mod_9() -> Z9 {
+    Z9::new([1, 2, 4, 5, 7, 8][next(6) as usize])
+}
+
+fn is_field<T: Field>() {}
+fn is_commutative_ring<T: CommutativeRing>() {}
+fn is_ring<T: Ring>() {}
+fn is_additive_abelian_group<T: AdditiveAbelianGroup>() {}
+fn is_integral_domain<T: IntegralDomain>() {}
+fn has_commutative_associative_addition<T: CommutativeAddition + AssociativeAddition>() {}
+fn has_no_zero_divisors<T: NoZeroDivisors>() {}
+
+#[test]
+fn derives_classify_the_types() {
+    is_field::<F13>();
+    is_integral_domain::<F13>();
+    is_commutative_ring::<Z6>();
+    is_commutative_ring::<Z8>();
+    is_additive_abelian_group::<Z10>();
+    has_commutative_associative_addition::<Z10>();
+    is_ring::<Z9>();
+    has_no_zero_divisors::<Z9>();
+}
+
+#[test]
+fn derived_law_modules_run_the_checkers() {
+    // The generated modules are private to this file; running the same checkers here makes sure
+    // the generators satisfy the derived promises independently of the generated tests.
+    noether::laws::check_field(f13, 300).unwrap();
+    noether::laws::check_commutative_ring(z6, 1000).unwrap();
+    noether::laws::check_distributive(z8, 200).unwrap();
+    noether::laws::check_no_zero_divisors(units_mod_9, 1000).unwrap();
+}
+
+#[test]
+fn broken_promises_are_caught() {
+    // The promise of Z9 only holds on its units: 3 · 3 = 0.
+    let violation = noether::laws::check_no_zero_divisors(|| Z9::new(next(9)), 1000).unwrap_err();
+    assert!((violation.a * violation.b.unwrap()).is_zero());
+}
+
+/// A product of a field and a ring with zero divisors.
+#[derive(Clone, Copy, Debug, PartialEq, noether::ComponentWise)]
+struct Pair {
+    a: F13,
+    b: Z6,
+}
+
+/// A newtype around a field, which keeps division and the absence of zero divisors.
+#[derive(Clone, Copy, Debug, PartialEq, noether::ComponentWise)]
+#[component_wise(select, exact_division)]
+struct Wrapped(noether::Fp<7>);
+
+/// A generic product.
+#[derive(Clone, Copy, Debug, PartialEq, noether::ComponentWise)]
+#[component_wise(select, exact_division)]
+struct Square<T>(T, T);
+
+fn is_multiplicative_abelian_group<T: noether::MultiplicativeAbelianGroup>() {}
+
+#[test]
+fn component_wise_products_are_rings() {
+    is_commutative_ring::<Pair>();
+    is_commutative_ring::<Square<Z8>>();
+    is_ring::<Square<Square<F13>>>();
+    noether::laws::check_commutative_ring(|| Pair { a: f13(), b: z6() }, 1000).unwrap();
+    noether::laws::check_commutative_ring(|| Square(z8(), z8()), 1000).unwrap();
+
+    let x = Pair {
+        a: F13(4),
+        b: Z6(5),
+    };
+    let y = Pair {
+        a: F13(10),
+        b: Z6(3),
+    };
+    assert_eq!(
+        x + y,
+        Pair {
+            a: F13(1),
+            b: Z6(2)
+        }
+    );
+    assert_eq!(
+        x - y,
+        Pair {
+            a: F13(7),
+            b: Z6(2)
+        }
+    );
+    assert_eq!(
+        x * y,
+        Pair {
+            a: F13(1),
+            b: Z6(3)
+        }
+    );
+    assert_eq!(
+        -x,
+        Pair {
+            a: F13(9),
+            b: Z6(1)
+        }
+    );
+    assert!(Pair::zero().is_zero() && !Pair::one().is_zero());
+
+    // (1, 0) · (0, 1) = (0, 0), so the product is not an integral domain.
+    let left = Pair {
+        a: F13(1),
+        b: Z6(0),
+    };
+    let right = Pair {
+        a: F13(0),
+        b: Z6(1),
+    };
+    assert!((left * right).is_zero());
+}
+
+#[test]
+fn component_wise_newtypes_keep_division() {
+    is_integral_domain::<Wrapped>();
+    is_multiplicative_abelian_group::<Wrapped>();
+
+    let x = Wrapped(noether::Fp::new(3));
+    assert_eq!(x.inv(), Wrapped(noether::Fp::new(5)));
+    assert_eq!(Wrapped::one() / x, x.inv());
+    let mut y = Wrapped(noether::Fp::new(6));
+    y /= x;
+    assert_eq!(y, Wrapped(noether::Fp::new(2)));
+    noether::laws::check_no_zero_divisors(|| Wrapped(noether::Fp::new(next(7) as u64)), 500)
+        .unwrap();
+}
+
+#[test]
+fn component_wise_structs_divide_exactly_by_component() {
+    let x = Square(noether::Zn::<7>::new(6), noether::Zn::new(3));
+    assert_eq!(
+        x.div_exact(3),
+        Some(Square(noether::Zn::new(2), noether::Zn::new(1)))
+    );
+    assert_eq!(
+        Wrapped(noether::Fp::new(1)).div_exact(2),
+        Some(Wrapped(noether::Fp::new(4)))
+    );
+    // 2 is not a unit of ℤ/6, so dividing by it is not unique.
+    let y = Square(noether::Zn::<6>::new(4), noether::Zn::new(2));
+    assert_eq!(y.div_exact(2), None);
+}
+
+// Component-wise structs are bounded when a field is.
+const _: () = assert!(!<Square<noether::Zn<7>> as AssociativeAddition>::BOUNDED);
+const _: () = assert!(<Square<noether::Checked<i64>> as AssociativeAddition>::BOUNDED);
+
+/// A length which can be added and negated, but neither multiplied nor cloned.
+#[derive(Debug, PartialEq)]
+struct Meters(i64);
+
+impl Add for Meters {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Meters(self.0 + rhs.0)
+    }
+}
+
+impl Neg for Meters {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Meters(-self.0)
+    }
+}
+
+impl Zero for Meters {
+    fn zero() -> Self {
+        Meters(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+/// A product of additive fields without `Clone`, which only gets the additive impls.
+#[derive(Debug, PartialEq, noether::ComponentWise)]
+struct Displacement {
+    x: Meters,
+    y: Meters,
+}
+
+#[test]
+fn component_wise_forwards_only_what_the_fields_support() {
+    let a = Displacement {
+        x: Meters(3),
+        y: Meters(-4),
+    };
+    let b = Displacement {
+        x: Meters(1),
+        y: Meters(1),
+    };
+    assert_eq!(
+        a + b,
+        Displacement {
+            x: Meters(4),
+            y: Meters(-3)
+        }
+    );
+    assert_eq!(
+        -Displacement {
+            x: Meters(2),
+            y: Meters(0)
+        },
+        Displacement {
+            x: Meters(-2),
+            y: Meters(0)
+        }
+    );
+    assert!(Displacement::zero().is_zero());
+}